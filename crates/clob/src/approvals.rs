@@ -0,0 +1,260 @@
+use crate::{
+    POLYGON_COLLATERAL_CONTRACT, POLYGON_CONDITIONAL_TOKEN_CONTRACT, POLYGON_EXCHANGE_CONTRACT,
+    POLYGON_NEG_RISK_ADAPTER_CONTRACT, POLYGON_NEG_RISK_EXCHANGE_CONTRACT, send_and_confirm,
+};
+use alloy_primitives::{Address, Bytes, TxHash, U256};
+use alloy_provider::Provider;
+use alloy_rpc_types_eth::TransactionRequest;
+use alloy_sol_types::{SolCall, sol};
+use anyhow::{Error, Result};
+
+sol! {
+    interface IERC20 {
+        function approve(address spender, uint256 amount) external returns (bool);
+        function allowance(address owner, address spender) external view returns (uint256);
+    }
+
+    interface IERC1155 {
+        function setApprovalForAll(address operator, bool approved) external;
+        function isApprovedForAll(address account, address operator) external view returns (bool);
+    }
+}
+
+/// A single approval transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApprovalCall {
+    /// USDC `approve(spender, amount)` on the collateral token
+    Collateral { token: Address, spender: Address, amount: U256 },
+    /// CTF `setApprovalForAll(operator, true)` on the conditional tokens
+    Conditional { token: Address, operator: Address },
+}
+
+impl ApprovalCall {
+    /// Contract the call is sent to
+    pub const fn to(&self) -> Address {
+        match self {
+            Self::Collateral { token, .. } | Self::Conditional { token, .. } => *token,
+        }
+    }
+
+    pub fn calldata(&self) -> Bytes {
+        match *self {
+            Self::Collateral { spender, amount, .. } => {
+                IERC20::approveCall { spender, amount }.abi_encode().into()
+            }
+            Self::Conditional { operator, .. } => {
+                IERC1155::setApprovalForAllCall { operator, approved: true }.abi_encode().into()
+            }
+        }
+    }
+
+    pub fn into_transaction(self, from: Address) -> TransactionRequest {
+        TransactionRequest::default().from(from).to(self.to()).input(self.calldata().into())
+    }
+}
+
+/// Current approvals of one spender.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ApprovalStatus {
+    pub spender: Address,
+    /// USDC the spender may move, in raw 6-decimal units
+    pub collateral_allowance: U256,
+    pub conditional_approved: bool,
+}
+
+/// Approvals a wallet needs before trading: USDC `approve` and CTF `setApprovalForAll` for the
+/// exchange, the neg-risk exchange and the neg-risk adapter.
+///
+/// The transactions are sent from `owner`, so this only covers EOA wallets; proxy and Safe
+/// wallets must relay the same calls through the wallet. Point the token and spender addresses
+/// at local deployments to run against an anvil node.
+#[derive(Debug, Clone)]
+pub struct Approvals {
+    pub owner: Address,
+    pub collateral: Address,
+    pub conditional_tokens: Address,
+    pub spenders: Vec<Address>,
+    /// USDC allowance granted to each spender, unlimited by default
+    pub amount: U256,
+    /// Allowance below which a spender is re-approved (capped at `amount`). Fills spend the
+    /// allowance, so an unlimited approval is only renewed once half of it is used.
+    pub min_allowance: U256,
+}
+
+impl Approvals {
+    /// Polygon mainnet approvals for `owner`.
+    pub fn new(owner: Address) -> Self {
+        Self {
+            owner,
+            collateral: POLYGON_COLLATERAL_CONTRACT,
+            conditional_tokens: POLYGON_CONDITIONAL_TOKEN_CONTRACT,
+            spenders: vec![
+                POLYGON_EXCHANGE_CONTRACT,
+                POLYGON_NEG_RISK_EXCHANGE_CONTRACT,
+                POLYGON_NEG_RISK_ADAPTER_CONTRACT,
+            ],
+            amount: U256::MAX,
+            min_allowance: U256::MAX >> 1,
+        }
+    }
+
+    /// Every approval call, collateral first, whether or not it is already in place.
+    pub fn calls(&self) -> Vec<ApprovalCall> {
+        let collateral = self.spenders.iter().map(|&spender| ApprovalCall::Collateral {
+            token: self.collateral,
+            spender,
+            amount: self.amount,
+        });
+        let conditional = self.spenders.iter().map(|&operator| ApprovalCall::Conditional {
+            token: self.conditional_tokens,
+            operator,
+        });
+        collateral.chain(conditional).collect()
+    }
+
+    pub fn transactions(&self) -> Vec<TransactionRequest> {
+        self.calls().into_iter().map(|call| call.into_transaction(self.owner)).collect()
+    }
+
+    /// Reads the current allowances of every spender.
+    pub async fn status<P: Provider>(&self, provider: &P) -> Result<Vec<ApprovalStatus>> {
+        let mut statuses = Vec::with_capacity(self.spenders.len());
+        for &spender in &self.spenders {
+            let allowance = IERC20::allowanceCall { owner: self.owner, spender };
+            let approved =
+                IERC1155::isApprovedForAllCall { account: self.owner, operator: spender };
+            statuses.push(ApprovalStatus {
+                spender,
+                collateral_allowance: eth_call(provider, self.collateral, allowance).await?,
+                conditional_approved: eth_call(provider, self.conditional_tokens, approved).await?,
+            });
+        }
+        Ok(statuses)
+    }
+
+    /// Calls still needed: collateral allowances below `min_allowance` (or `amount` if lower)
+    /// and missing CTF approvals.
+    pub async fn missing<P: Provider>(&self, provider: &P) -> Result<Vec<ApprovalCall>> {
+        let statuses = self.status(provider).await?;
+        let min_allowance = self.min_allowance.min(self.amount);
+        let calls = self.calls().into_iter().filter(|call| {
+            statuses.iter().any(|status| match *call {
+                ApprovalCall::Collateral { spender, .. } => {
+                    status.spender == spender && status.collateral_allowance < min_allowance
+                }
+                ApprovalCall::Conditional { operator, .. } => {
+                    status.spender == operator && !status.conditional_approved
+                }
+            })
+        });
+        Ok(calls.collect())
+    }
+
+    /// Sends the missing approvals one by one through `provider` (which must sign for `owner`)
+    /// and waits for each to be mined, returning the transaction hashes.
+    pub async fn send_missing<P: Provider>(&self, provider: &P) -> Result<Vec<TxHash>> {
+        let mut hashes = Vec::new();
+        for call in self.missing(provider).await? {
+            hashes.push(send_and_confirm(provider, call.into_transaction(self.owner)).await?);
+        }
+        Ok(hashes)
+    }
+}
+
+async fn eth_call<P: Provider, C: SolCall>(
+    provider: &P,
+    to: Address,
+    call: C,
+) -> Result<C::Return> {
+    let request = TransactionRequest::default().to(to).input(call.abi_encode().into());
+    let output = provider.call(request).await?;
+    C::abi_decode_returns(&output)
+        .map_err(|e| Error::msg(format!("Invalid {} return data: {e}", C::SIGNATURE)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::serve;
+    use alloy_primitives::hex;
+    use alloy_provider::ProviderBuilder;
+    use serde_json::{Value, json};
+
+    /// JSON-RPC stand-in for a node: `allowances` and `approved` are indexed like the spenders.
+    async fn node(spenders: Vec<Address>, allowances: Vec<U256>, approved: Vec<bool>) -> String {
+        serve(move |_, body| {
+            let request: Value = serde_json::from_str(body).unwrap();
+            let call = &request["params"][0];
+            let input = call["input"].as_str().or(call["data"].as_str()).unwrap_or_default();
+            let input = hex::decode(input).unwrap_or_default();
+
+            let result = match request["method"].as_str() {
+                Some("eth_chainId") => json!("0x89"),
+                Some("eth_call") => {
+                    // Both calls take (owner, spender)
+                    let spender = Address::from_slice(&input[48..68]);
+                    let index = spenders.iter().position(|&s| s == spender).unwrap();
+                    let word = if input[..4] == IERC20::allowanceCall::SELECTOR {
+                        allowances[index]
+                    } else {
+                        U256::from(approved[index] as u8)
+                    };
+                    json!(hex::encode_prefixed(word.to_be_bytes::<32>()))
+                }
+                method => panic!("Unexpected method {method:?}"),
+            };
+            (200, json!({ "jsonrpc": "2.0", "id": request["id"], "result": result }).to_string())
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn missing_skips_partly_spent_unlimited_allowances() {
+        let approvals = Approvals::new(Address::repeat_byte(0x01));
+        let neg_risk_exchange = approvals.spenders[1];
+        let spent = U256::MAX - U256::from(5_000_000);
+        let url = node(
+            approvals.spenders.clone(),
+            vec![spent, U256::ZERO, U256::MAX],
+            vec![true, false, true],
+        )
+        .await;
+        let provider = ProviderBuilder::new().connect_http(url.parse().unwrap());
+
+        let statuses = approvals.status(&provider).await.unwrap();
+        assert_eq!(statuses[0].collateral_allowance, spent);
+        assert!(!statuses[1].conditional_approved);
+
+        let missing = approvals.missing(&provider).await.unwrap();
+        assert_eq!(
+            missing,
+            vec![
+                ApprovalCall::Collateral {
+                    token: approvals.collateral,
+                    spender: neg_risk_exchange,
+                    amount: U256::MAX,
+                },
+                ApprovalCall::Conditional {
+                    token: approvals.conditional_tokens,
+                    operator: neg_risk_exchange,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn missing_uses_amount_when_below_threshold() {
+        let mut approvals = Approvals::new(Address::repeat_byte(0x01));
+        approvals.amount = U256::from(100_000_000);
+        let allowances = vec![U256::from(100_000_000), U256::from(99_999_999), U256::MAX];
+        let url = node(approvals.spenders.clone(), allowances, vec![true; 3]).await;
+        let provider = ProviderBuilder::new().connect_http(url.parse().unwrap());
+
+        let missing = approvals.missing(&provider).await.unwrap();
+        assert_eq!(missing.len(), 1);
+        assert!(matches!(
+            missing[0],
+            ApprovalCall::Collateral { spender, .. } if spender == approvals.spenders[1]
+        ));
+    }
+}
@@ -1,19 +1,20 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt};
 
 use crate::{
     POLY_ADDR_HEADER, POLY_NONCE_HEADER, POLY_SIG_HEADER, POLY_TS_HEADER, POLYGON_MAINNET_CHAIN_ID,
     get_current_unix_time_secs, into_result,
 };
 use alloy_primitives::{U256, hex::encode_prefixed};
-use alloy_signer::SignerSync;
+use alloy_signer::Signer;
 use alloy_signer_local::PrivateKeySigner;
-use alloy_sol_types::SolStruct;
 use alloy_sol_types::{eip712_domain, sol};
 use anyhow::{Error, Result};
 use reqwest::RequestBuilder;
 use serde::{Deserialize, Serialize};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
-#[derive(Clone, Serialize, Deserialize, Debug)]
+/// L2 API credentials. `secret` and `passphrase` are wiped on drop and redacted from `Debug`.
+#[derive(Clone, Serialize, Deserialize, Zeroize, ZeroizeOnDrop)]
 pub struct Credentials {
     #[serde(rename = "apiKey")]
     pub api_key: String,
@@ -27,20 +28,37 @@ impl Credentials {
     }
 }
 
-pub struct AuthenticatedClient {
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("api_key", &self.api_key)
+            .field("secret", &"<redacted>")
+            .field("passphrase", &"<redacted>")
+            .finish()
+    }
+}
+
+/// Client for the L1 (wallet-signed) CLOB endpoints.
+///
+/// Generic over any [`Signer`], so the wallet can be a raw private key, a keystore or
+/// mnemonic-derived wallet, or a remote signing service.
+pub struct AuthenticatedClient<S = PrivateKeySigner> {
     api_base: String,
     client: reqwest::Client,
-    wallet: PrivateKeySigner,
+    wallet: S,
 }
 
-impl AuthenticatedClient {
-    pub fn new(api_base: &str, wallet: PrivateKeySigner) -> Result<Self> {
+impl<S> AuthenticatedClient<S>
+where
+    S: Signer + Send + Sync,
+{
+    pub fn new(api_base: &str, wallet: S) -> Result<Self> {
         let client = reqwest::Client::builder().build()?;
         Ok(Self { api_base: api_base.to_string(), client, wallet })
     }
 
-    fn auth_request(&self, builder: RequestBuilder) -> Result<RequestBuilder> {
-        let headers = create_l1_headers(&self.wallet, POLYGON_MAINNET_CHAIN_ID, None)?;
+    async fn auth_request(&self, builder: RequestBuilder) -> Result<RequestBuilder> {
+        let headers = create_l1_headers(&self.wallet, POLYGON_MAINNET_CHAIN_ID, None).await?;
         let mut req = builder;
         for (k, v) in headers {
             req = req.header(k, v);
@@ -51,21 +69,24 @@ impl AuthenticatedClient {
     pub async fn derive_api_key(&self) -> Result<Credentials> {
         let url = format!("{}/auth/derive-api-key", self.api_base);
         let request = self.client.get(&url);
-        let request = self.auth_request(request)?;
+        let request = self.auth_request(request).await?;
 
         let response = request.send().await?;
         into_result(response).await
     }
 }
 
-pub fn create_l1_headers(
-    signer: &PrivateKeySigner,
+pub async fn create_l1_headers<S>(
+    signer: &S,
     chain_id: u64,
     nonce: Option<U256>,
-) -> Result<HashMap<&'static str, String>> {
+) -> Result<HashMap<&'static str, String>>
+where
+    S: Signer + Send + Sync,
+{
     let timestamp = get_current_unix_time_secs().to_string();
     let nonce_val = nonce.unwrap_or(U256::ZERO);
-    let signature = sign_clob_auth_message(signer, timestamp.clone(), nonce_val, chain_id)?;
+    let signature = sign_clob_auth_message(signer, timestamp.clone(), nonce_val, chain_id).await?;
     let address = encode_prefixed(signer.address().as_slice());
 
     Ok(HashMap::from([
@@ -85,12 +106,15 @@ sol! {
     }
 }
 
-pub fn sign_clob_auth_message(
-    signer: &PrivateKeySigner,
+pub async fn sign_clob_auth_message<S>(
+    signer: &S,
     timestamp: String,
     nonce: U256,
     chain_id: u64,
-) -> Result<String> {
+) -> Result<String>
+where
+    S: Signer + Send + Sync,
+{
     let message = "This message attests that I control the given wallet".to_owned();
 
     let auth_struct = ClobAuth { address: signer.address(), timestamp, nonce, message };
@@ -101,9 +125,9 @@ pub fn sign_clob_auth_message(
         chain_id: chain_id,
     );
 
-    let hash = auth_struct.eip712_signing_hash(&domain);
     let signature = signer
-        .sign_hash_sync(&hash)
+        .sign_typed_data(&auth_struct, &domain)
+        .await
         .map_err(|e| Error::msg(format!("Failed to sign auth message: {e}")))?;
 
     Ok(encode_prefixed(signature.as_bytes()))
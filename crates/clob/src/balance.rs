@@ -0,0 +1,76 @@
+use crate::{SignatureType, TokenId, deserialize_token_amount, deserialize_token_amount_map};
+use alloy_primitives::Address;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum AssetType {
+    /// USDC collateral
+    Collateral,
+    /// Outcome shares of a single CLOB token
+    Conditional,
+}
+
+/// Query of the `/balance-allowance` endpoints.
+#[derive(Debug, Clone, Serialize)]
+pub struct BalanceAllowanceParams {
+    pub asset_type: AssetType,
+    /// Required for [`AssetType::Conditional`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_id: Option<TokenId>,
+    pub signature_type: u8,
+}
+
+impl BalanceAllowanceParams {
+    pub fn new(asset_type: AssetType, token_id: Option<TokenId>, sig_type: SignatureType) -> Self {
+        Self { asset_type, token_id, signature_type: sig_type.to_u8() }
+    }
+}
+
+/// Balance and per-spender allowances, in whole tokens (raw amounts divided by `TOKEN_SCALE`).
+///
+/// Unlimited allowances saturate at `Decimal::MAX`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct BalanceAllowance {
+    #[serde(deserialize_with = "deserialize_token_amount")]
+    pub balance: Decimal,
+    #[serde(default, deserialize_with = "deserialize_token_amount_map")]
+    pub allowances: HashMap<Address, Decimal>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        POLYGON_EXCHANGE_CONTRACT, POLYGON_NEG_RISK_ADAPTER_CONTRACT,
+        POLYGON_NEG_RISK_EXCHANGE_CONTRACT,
+    };
+    use std::str::FromStr;
+
+    #[test]
+    fn deserializes_balance_allowance_response() {
+        let response = r#"{
+            "balance": "1234567890",
+            "allowances": {
+                "0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E": "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+                "0xC5d563A36AE78145C45a50134d48A1215220f80a": "0",
+                "0xd91E80cF2E7be2e162c6513ceD06f1dD0dA35296": "2500000"
+            }
+        }"#;
+        let balance: BalanceAllowance = serde_json::from_str(response).unwrap();
+
+        assert_eq!(balance.balance, Decimal::from_str("1234.56789").unwrap());
+        assert_eq!(balance.allowances[&POLYGON_EXCHANGE_CONTRACT], Decimal::MAX);
+        assert_eq!(balance.allowances[&POLYGON_NEG_RISK_EXCHANGE_CONTRACT], Decimal::ZERO);
+        assert_eq!(balance.allowances[&POLYGON_NEG_RISK_ADAPTER_CONTRACT], Decimal::new(25, 1));
+    }
+
+    #[test]
+    fn conditional_response_may_omit_allowances() {
+        let balance: BalanceAllowance = serde_json::from_str(r#"{"balance": "500"}"#).unwrap();
+        assert_eq!(balance.balance, Decimal::new(5, 4));
+        assert!(balance.allowances.is_empty());
+    }
+}
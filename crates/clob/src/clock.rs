@@ -0,0 +1,51 @@
+use crate::get_current_unix_time_secs;
+use anyhow::{Error, Result};
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+
+/// Security threshold the CLOB subtracts from a GTD order's `expiration` (seconds).
+///
+/// An order meant to live for 90 seconds must carry `now + 60 + 90` as its expiration.
+pub const GTD_EXPIRATION_BUFFER_SECS: u64 = 60;
+
+/// Local clock corrected by the offset to the CLOB server time (see `TradingClient::sync_clock`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ServerClock {
+    offset_secs: i64,
+}
+
+impl ServerClock {
+    /// Clock without correction, trusting the local system time.
+    pub const fn local() -> Self {
+        Self { offset_secs: 0 }
+    }
+
+    /// Builds a clock from a server timestamp (Unix seconds) observed just now.
+    pub fn from_server_time(server_secs: u64) -> Self {
+        let offset_secs = server_secs as i64 - get_current_unix_time_secs() as i64;
+        Self { offset_secs }
+    }
+
+    pub const fn offset_secs(&self) -> i64 {
+        self.offset_secs
+    }
+
+    /// Current server time in Unix seconds.
+    pub fn now_secs(&self) -> u64 {
+        get_current_unix_time_secs().saturating_add_signed(self.offset_secs)
+    }
+
+    /// GTD `expiration` for an order that should stay live for `lifetime` from now.
+    pub fn expires_in(&self, lifetime: Duration) -> u64 {
+        self.now_secs() + GTD_EXPIRATION_BUFFER_SECS + lifetime.as_secs()
+    }
+
+    /// GTD `expiration` for an order that should stay live until `at`.
+    pub fn expires_at(&self, at: DateTime<Utc>) -> Result<u64> {
+        let at_secs = u64::try_from(at.timestamp()).unwrap_or_default();
+        if at_secs <= self.now_secs() {
+            return Err(Error::msg(format!("Expiration {at} is not in the future")));
+        }
+        Ok(at_secs + GTD_EXPIRATION_BUFFER_SECS)
+    }
+}
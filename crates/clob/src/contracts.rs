@@ -1,4 +1,4 @@
-use alloy_primitives::{Address, address};
+use alloy_primitives::{Address, B256, address, b256};
 use rust_decimal::Decimal;
 
 /// WebSocket endpoint for RTSD (Real-Time Streaming Data)
@@ -27,13 +27,37 @@ pub const POLYGON_COLLATERAL_CONTRACT: Address =
 pub const POLYGON_CONDITIONAL_TOKEN_CONTRACT: Address =
     address!("4D97DCd97eC945f40cF65F87097ACe5EA0476045");
 
-/// Neg-Risk (negative risk) exchange adapter contract
+/// Neg-Risk (negative risk) CTF exchange contract, verifying orders of multi-outcome markets
 pub const POLYGON_NEG_RISK_EXCHANGE_CONTRACT: Address =
     address!("C5d563A36AE78145C45a50134d48A1215220f80a");
 
+/// Neg-Risk adapter, wrapping collateral and converting positions of multi-outcome markets
+pub const POLYGON_NEG_RISK_ADAPTER_CONTRACT: Address =
+    address!("d91E80cF2E7be2e162c6513ceD06f1dD0dA35296");
+
+/// Wrapped USDC the neg-risk adapter splits positions from, the collateral of neg-risk tokens
+pub const POLYGON_NEG_RISK_WRAPPED_COLLATERAL_CONTRACT: Address =
+    address!("3A3BD7bb9528E159577F7C2e685CC81A765002E2");
+
 /// Main Polymarket exchange contract (yes/no markets)
 pub const POLYGON_EXCHANGE_CONTRACT: Address = address!("4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E");
 
+/// Factory deploying Polymarket proxy wallets (Magic / email logins)
+pub const POLYGON_PROXY_FACTORY_CONTRACT: Address =
+    address!("aB45c5A4B0c941a2F231C04C3f49182e1A254052");
+
+/// Init code hash of the Polymarket proxy wallet, used for CREATE2 derivation
+pub const POLYGON_PROXY_INIT_CODE_HASH: B256 =
+    b256!("d21df8dc65880a8606f09fe0ce3df9b8869287ab0b058be05aa9e8af6330a00b");
+
+/// Factory deploying Polymarket Gnosis Safe wallets (browser wallet logins)
+pub const POLYGON_SAFE_FACTORY_CONTRACT: Address =
+    address!("aacFeEa03eb1561C4e67d661e40682Bd20E3541b");
+
+/// Init code hash of the Polymarket Gnosis Safe proxy, used for CREATE2 derivation
+pub const POLYGON_SAFE_INIT_CODE_HASH: B256 =
+    b256!("2bce2127ff07fb632d16c8347c4ebf501f4841168bed00d9e6ef715ddb6fcecf");
+
 /// Scaling factor for token amounts (USDC has 6 decimals → 1_000_000)
 pub const TOKEN_SCALE: Decimal = Decimal::from_parts(1_000_000, 0, 0, false, 0);
 
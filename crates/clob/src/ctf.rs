@@ -0,0 +1,366 @@
+use crate::{
+    ApprovalCall, ConditionId, POLYGON_COLLATERAL_CONTRACT, POLYGON_CONDITIONAL_TOKEN_CONTRACT,
+    POLYGON_NEG_RISK_ADAPTER_CONTRACT, POLYGON_NEG_RISK_WRAPPED_COLLATERAL_CONTRACT, TokenId,
+    send_and_confirm,
+};
+use alloy_primitives::{Address, B256, Bytes, TxHash, U256, keccak256};
+use alloy_provider::Provider;
+use alloy_rpc_types_eth::TransactionRequest;
+use alloy_sol_types::{SolCall, sol};
+use anyhow::{Result, bail};
+
+sol! {
+    interface IConditionalTokens {
+        function splitPosition(
+            address collateralToken,
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256[] partition,
+            uint256 amount
+        ) external;
+        function mergePositions(
+            address collateralToken,
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256[] partition,
+            uint256 amount
+        ) external;
+        function redeemPositions(
+            address collateralToken,
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256[] indexSets
+        ) external;
+    }
+
+    interface INegRiskAdapter {
+        function splitPosition(bytes32 conditionId, uint256 amount) external;
+        function mergePositions(bytes32 conditionId, uint256 amount) external;
+        function redeemPositions(bytes32 conditionId, uint256[] amounts) external;
+        function convertPositions(bytes32 marketId, uint256 indexSet, uint256 amount) external;
+    }
+}
+
+/// Index sets of the two outcomes of a binary condition: `0b01` and `0b10`.
+pub const BINARY_PARTITION: [U256; 2] =
+    [U256::from_limbs([1, 0, 0, 0]), U256::from_limbs([2, 0, 0, 0])];
+
+/// An encoded Conditional Tokens (or neg-risk adapter) call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CtfCall {
+    pub to: Address,
+    pub data: Bytes,
+}
+
+impl CtfCall {
+    pub fn into_transaction(self, from: Address) -> TransactionRequest {
+        TransactionRequest::default().from(from).to(self.to).input(self.data.into())
+    }
+
+    /// Sends the call from `from` through `provider` (which must sign for it) and waits for it
+    /// to be mined.
+    pub async fn send<P: Provider>(self, provider: &P, from: Address) -> Result<TxHash> {
+        send_and_confirm(provider, self.into_transaction(from)).await
+    }
+}
+
+/// Builds split, merge, redeem and convert calls for binary Polymarket markets.
+///
+/// Standard markets go straight to the Conditional Tokens contract, which pulls USDC on split and
+/// so needs the allowance from [`Ctf::collateral_approval`]. Neg-risk markets go through the
+/// neg-risk adapter, which `Approvals` already covers. Amounts are raw 6-decimal units, see `decimal_to_token_amount`.
+#[derive(Debug, Clone, Copy)]
+pub struct Ctf {
+    pub collateral: Address,
+    pub conditional_tokens: Address,
+    pub neg_risk_adapter: Address,
+    /// Collateral of neg-risk outcome tokens, which the adapter splits from wrapped USDC
+    pub wrapped_collateral: Address,
+}
+
+impl Default for Ctf {
+    fn default() -> Self {
+        Self {
+            collateral: POLYGON_COLLATERAL_CONTRACT,
+            conditional_tokens: POLYGON_CONDITIONAL_TOKEN_CONTRACT,
+            neg_risk_adapter: POLYGON_NEG_RISK_ADAPTER_CONTRACT,
+            wrapped_collateral: POLYGON_NEG_RISK_WRAPPED_COLLATERAL_CONTRACT,
+        }
+    }
+}
+
+impl Ctf {
+    /// Polygon mainnet contracts.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// USDC approval letting the Conditional Tokens contract pull `amount` on standard splits.
+    pub const fn collateral_approval(&self, amount: U256) -> ApprovalCall {
+        ApprovalCall::Collateral {
+            token: self.collateral,
+            spender: self.conditional_tokens,
+            amount,
+        }
+    }
+
+    /// Splits `amount` USDC into `amount` of each outcome token.
+    pub fn split(&self, condition_id: ConditionId, amount: U256, neg_risk: bool) -> CtfCall {
+        if neg_risk {
+            let call = INegRiskAdapter::splitPositionCall { conditionId: condition_id.0, amount };
+            return self.adapter_call(call);
+        }
+        self.ctf_call(IConditionalTokens::splitPositionCall {
+            collateralToken: self.collateral,
+            parentCollectionId: B256::ZERO,
+            conditionId: condition_id.0,
+            partition: BINARY_PARTITION.to_vec(),
+            amount,
+        })
+    }
+
+    /// Merges `amount` of each outcome token back into `amount` USDC.
+    pub fn merge(&self, condition_id: ConditionId, amount: U256, neg_risk: bool) -> CtfCall {
+        if neg_risk {
+            let call = INegRiskAdapter::mergePositionsCall { conditionId: condition_id.0, amount };
+            return self.adapter_call(call);
+        }
+        self.ctf_call(IConditionalTokens::mergePositionsCall {
+            collateralToken: self.collateral,
+            parentCollectionId: B256::ZERO,
+            conditionId: condition_id.0,
+            partition: BINARY_PARTITION.to_vec(),
+            amount,
+        })
+    }
+
+    /// Redeems the whole balance of both outcome tokens of a resolved standard market.
+    pub fn redeem(&self, condition_id: ConditionId) -> CtfCall {
+        self.ctf_call(IConditionalTokens::redeemPositionsCall {
+            collateralToken: self.collateral,
+            parentCollectionId: B256::ZERO,
+            conditionId: condition_id.0,
+            indexSets: BINARY_PARTITION.to_vec(),
+        })
+    }
+
+    /// Redeems `[yes, no]` amounts of a resolved neg-risk market.
+    pub fn redeem_neg_risk(&self, condition_id: ConditionId, amounts: [U256; 2]) -> CtfCall {
+        self.adapter_call(INegRiskAdapter::redeemPositionsCall {
+            conditionId: condition_id.0,
+            amounts: amounts.to_vec(),
+        })
+    }
+
+    /// Converts `amount` NO tokens of each question in `index_set` (bit `i` for question `i`) of
+    /// the neg-risk market `market_id` into YES tokens of every other question, plus USDC when
+    /// more than one NO is converted.
+    pub fn convert(&self, market_id: B256, index_set: U256, amount: U256) -> CtfCall {
+        self.adapter_call(INegRiskAdapter::convertPositionsCall {
+            marketId: market_id,
+            indexSet: index_set,
+            amount,
+        })
+    }
+
+    /// Collateral the outcome tokens of a market are backed by.
+    pub const fn position_collateral(&self, neg_risk: bool) -> Address {
+        if neg_risk { self.wrapped_collateral } else { self.collateral }
+    }
+
+    /// Token ids of the two outcomes of `condition_id`, in outcome order (`[yes, no]`).
+    pub fn token_ids(&self, condition_id: ConditionId, neg_risk: bool) -> [TokenId; 2] {
+        let collateral = self.position_collateral(neg_risk);
+        BINARY_PARTITION
+            .map(|index_set| position_id(collateral, collection_id(condition_id, index_set)))
+    }
+
+    /// Checks that `token_ids` (e.g. a Gamma market's `clob_token_ids`) are the outcome tokens of
+    /// `condition_id`, in outcome order.
+    pub fn verify_token_ids(
+        &self,
+        condition_id: ConditionId,
+        neg_risk: bool,
+        token_ids: &[TokenId],
+    ) -> Result<()> {
+        let expected = self.token_ids(condition_id, neg_risk);
+        if token_ids != expected {
+            bail!(
+                "Token ids {token_ids:?} do not match condition {condition_id}, expected {expected:?}"
+            );
+        }
+        Ok(())
+    }
+
+    fn ctf_call(&self, call: impl SolCall) -> CtfCall {
+        CtfCall { to: self.conditional_tokens, data: call.abi_encode().into() }
+    }
+
+    fn adapter_call(&self, call: impl SolCall) -> CtfCall {
+        CtfCall { to: self.neg_risk_adapter, data: call.abi_encode().into() }
+    }
+}
+
+/// Field modulus of the alt_bn128 curve `y^2 = x^3 + 3` the CTF encodes collection ids on.
+const BN128_P: U256 = U256::from_limbs([
+    0x3c208c16d87cfd47,
+    0x97816a916871ca8d,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+]);
+
+/// CTF collection id of the outcomes in `index_set` of `condition_id`, with no parent
+/// collection (as every Polymarket position has), mirroring `CTHelpers.getCollectionId`.
+pub fn collection_id(condition_id: ConditionId, index_set: U256) -> B256 {
+    let hash = keccak256([condition_id.0.as_slice(), &index_set.to_be_bytes::<32>()].concat());
+    let mut x = U256::from_be_bytes(hash.0);
+    let odd = x.bit(255);
+
+    // Smallest x past the hash that is on the curve
+    let (mut y, mut yy);
+    loop {
+        x = x.add_mod(U256::from(1), BN128_P);
+        yy = x.mul_mod(x, BN128_P).mul_mod(x, BN128_P).add_mod(U256::from(3), BN128_P);
+        y = yy.pow_mod((BN128_P + U256::from(1)) >> 2, BN128_P);
+        if y.mul_mod(y, BN128_P) == yy {
+            break;
+        }
+    }
+    if odd != y.bit(0) {
+        y = BN128_P - y;
+    }
+    if y.bit(0) {
+        x ^= U256::from(1) << 254;
+    }
+    B256::from(x)
+}
+
+/// ERC-1155 position id, i.e. the CLOB token id, of `collection_id` backed by `collateral`.
+pub fn position_id(collateral: Address, collection_id: B256) -> TokenId {
+    let hash = keccak256([collateral.as_slice(), collection_id.as_slice()].concat());
+    TokenId(U256::from_be_bytes(hash.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::hex;
+
+    const CONDITION: &str = "1111111111111111111111111111111111111111111111111111111111111111";
+    const USDC: &str = "0000000000000000000000002791bca1f2de4661ed88a30c99a7a9449aa84174";
+    const ZERO: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+    fn word(value: u64) -> String {
+        format!("{value:064x}")
+    }
+
+    fn calldata(selector: &str, words: &[&str]) -> Bytes {
+        hex::decode(format!("{selector}{}", words.concat())).unwrap().into()
+    }
+
+    fn condition_id() -> ConditionId {
+        CONDITION.parse().unwrap()
+    }
+
+    #[test]
+    fn encodes_standard_market_calls() {
+        let ctf = Ctf::new();
+        let amount = U256::from(1_000_000);
+        let (offset, len, one, two) = (word(0xa0), word(2), word(1), word(2));
+        let partition_tail = [len.as_str(), &one, &two];
+
+        let split = ctf.split(condition_id(), amount, false);
+        assert_eq!(split.to, POLYGON_CONDITIONAL_TOKEN_CONTRACT);
+        let head = [USDC, ZERO, CONDITION, &offset, &word(1_000_000)];
+        assert_eq!(split.data, calldata("72ce4275", &[&head[..], &partition_tail[..]].concat()));
+
+        let merge = ctf.merge(condition_id(), amount, false);
+        assert_eq!(merge.to, POLYGON_CONDITIONAL_TOKEN_CONTRACT);
+        assert_eq!(merge.data, calldata("9e7212ad", &[&head[..], &partition_tail[..]].concat()));
+
+        let redeem = ctf.redeem(condition_id());
+        assert_eq!(redeem.to, POLYGON_CONDITIONAL_TOKEN_CONTRACT);
+        let head = [USDC, ZERO, CONDITION, &word(0x80)];
+        assert_eq!(redeem.data, calldata("01b7037c", &[&head[..], &partition_tail[..]].concat()));
+    }
+
+    #[test]
+    fn encodes_neg_risk_adapter_calls() {
+        let ctf = Ctf::new();
+        let amount = U256::from(5_000_000);
+
+        let split = ctf.split(condition_id(), amount, true);
+        assert_eq!(split.to, POLYGON_NEG_RISK_ADAPTER_CONTRACT);
+        assert_eq!(split.data, calldata("a3d7da1d", &[CONDITION, &word(5_000_000)]));
+
+        let merge = ctf.merge(condition_id(), amount, true);
+        assert_eq!(merge.to, POLYGON_NEG_RISK_ADAPTER_CONTRACT);
+        assert_eq!(merge.data, calldata("b10c5c17", &[CONDITION, &word(5_000_000)]));
+
+        let redeem = ctf.redeem_neg_risk(condition_id(), [U256::from(3), U256::from(4)]);
+        assert_eq!(redeem.to, POLYGON_NEG_RISK_ADAPTER_CONTRACT);
+        let words = [CONDITION, &word(0x40), &word(2), &word(3), &word(4)];
+        assert_eq!(redeem.data, calldata("dbeccb23", &words));
+
+        let convert = ctf.convert(condition_id().0, U256::from(0b101), amount);
+        assert_eq!(convert.to, POLYGON_NEG_RISK_ADAPTER_CONTRACT);
+        assert_eq!(convert.data, calldata("c64748c4", &[CONDITION, &word(5), &word(5_000_000)]));
+    }
+
+    /// Condition and Gamma `clobTokenIds` of a standard binary market.
+    const BINARY_CONDITION: &str =
+        "0xe3b423dfad8c22ff75c9899c4e8176f628cf4ad4caa00481764d320e7415f7a9";
+    const BINARY_TOKENS: [&str; 2] = [
+        "53135072462907880191400140706440867753044989936304433583131786753949599718775",
+        "60869871469376321574904667328762911501870754872924453995477779862968218702336",
+    ];
+
+    /// Condition and Gamma `clobTokenIds` of "Donald Trump" in the neg-risk 2024 presidential
+    /// election event.
+    const NEG_RISK_CONDITION: &str =
+        "0xdd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917";
+    const NEG_RISK_TOKENS: [&str; 2] = [
+        "21742633143463906290569050155826241533067272736897614950488156847949938836455",
+        "48331043336612883890938759509493159234755048973500640148014422747788308965732",
+    ];
+
+    fn tokens(ids: [&str; 2]) -> [TokenId; 2] {
+        ids.map(|id| id.parse().unwrap())
+    }
+
+    #[test]
+    fn computes_binary_market_token_ids() {
+        let condition_id = BINARY_CONDITION.parse().unwrap();
+        assert_eq!(Ctf::new().token_ids(condition_id, false), tokens(BINARY_TOKENS));
+    }
+
+    #[test]
+    fn computes_neg_risk_market_token_ids() {
+        let condition_id = NEG_RISK_CONDITION.parse().unwrap();
+        assert_eq!(Ctf::new().token_ids(condition_id, true), tokens(NEG_RISK_TOKENS));
+        // The same condition backed by plain USDC gives unrelated ids
+        assert_ne!(Ctf::new().token_ids(condition_id, false), tokens(NEG_RISK_TOKENS));
+    }
+
+    #[test]
+    fn verify_token_ids_rejects_swapped_and_foreign_ids() {
+        let ctf = Ctf::new();
+        let condition_id = BINARY_CONDITION.parse().unwrap();
+        let [yes, no] = tokens(BINARY_TOKENS);
+
+        assert!(ctf.verify_token_ids(condition_id, false, &[yes, no]).is_ok());
+        assert!(ctf.verify_token_ids(condition_id, false, &[no, yes]).is_err());
+        assert!(ctf.verify_token_ids(condition_id, true, &[yes, no]).is_err());
+        assert!(ctf.verify_token_ids(condition_id, false, &tokens(NEG_RISK_TOKENS)).is_err());
+        assert!(ctf.verify_token_ids(condition_id, false, &[yes]).is_err());
+    }
+
+    #[test]
+    fn collateral_approval_targets_conditional_tokens() {
+        let approval = Ctf::new().collateral_approval(U256::from(7));
+        let spender = "0000000000000000000000004d97dcd97ec945f40cf65f87097ace5ea0476045";
+
+        assert_eq!(approval.to(), POLYGON_COLLATERAL_CONTRACT);
+        assert_eq!(approval.calldata(), calldata("095ea7b3", &[spender, &word(7)]));
+    }
+}
@@ -0,0 +1,293 @@
+use crate::TradingClient;
+use alloy_signer::Signer;
+use anyhow::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio::{
+    sync::{mpsc, oneshot},
+    task::JoinHandle,
+    time::{MissedTickBehavior, interval, timeout},
+};
+
+/// Events buffered for [`HeartbeatHandle::next_event`]; newer events are dropped while full.
+const HEARTBEAT_EVENT_CAPACITY: usize = 64;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HeartbeatRequest<'a> {
+    pub heartbeat_id: Option<&'a str>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Heartbeat {
+    pub heartbeat_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct HeartbeatConfig {
+    /// Time between beats, must stay well under the server's cancel timeout
+    pub interval: Duration,
+    /// Consecutive failed beats after which all orders are cancelled client-side
+    pub max_missed: u32,
+    /// Whether a graceful [`HeartbeatHandle::stop`] also cancels all orders
+    pub cancel_on_stop: bool,
+}
+
+impl Default for HeartbeatConfig {
+    fn default() -> Self {
+        Self { interval: Duration::from_secs(5), max_missed: 3, cancel_on_stop: true }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeartbeatStopReason {
+    /// [`HeartbeatHandle::stop`] was called
+    Shutdown,
+    /// `max_missed` consecutive beats failed
+    MissedBeats,
+    /// The [`HeartbeatHandle`] was dropped without `stop` or `detach`; orders are left to the
+    /// server's heartbeat timeout
+    Dropped,
+}
+
+/// Message from a [`HeartbeatHandle`] to its task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HeartbeatCommand {
+    Stop,
+    Detach,
+}
+
+#[derive(Debug, Clone)]
+pub enum HeartbeatEvent {
+    Beat { heartbeat_id: String },
+    Missed { consecutive: u32, error: String },
+    CancelledAll { reason: HeartbeatStopReason },
+    CancelFailed { reason: HeartbeatStopReason, error: String },
+    Stopped { reason: HeartbeatStopReason },
+}
+
+/// Handle of a running heartbeat task, see [`TradingClient::start_heartbeat`].
+///
+/// Dropping the handle stops the beats without calling `cancel_all`, so the server cancels the
+/// orders once its heartbeat timeout expires. Use [`HeartbeatHandle::detach`] to keep beating
+/// without a handle.
+#[derive(Debug)]
+pub struct HeartbeatHandle {
+    commands: Option<oneshot::Sender<HeartbeatCommand>>,
+    task: JoinHandle<()>,
+    events: mpsc::Receiver<HeartbeatEvent>,
+}
+
+impl HeartbeatHandle {
+    /// Next event of the task, `None` once it has finished and all events were read. Events
+    /// are dropped while more than 64 are unread.
+    pub async fn next_event(&mut self) -> Option<HeartbeatEvent> {
+        self.events.recv().await
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+
+    /// Stops the task (cancelling all orders if configured) and waits for it to finish.
+    pub async fn stop(mut self) -> Result<()> {
+        if let Some(commands) = self.commands.take() {
+            let _ = commands.send(HeartbeatCommand::Stop);
+        }
+        (&mut self.task).await.map_err(|e| Error::msg(format!("Heartbeat task failed: {e}")))
+    }
+
+    /// Lets the task beat for the rest of the process, until `max_missed` beats fail. Its
+    /// events are discarded.
+    pub fn detach(mut self) {
+        if let Some(commands) = self.commands.take() {
+            let _ = commands.send(HeartbeatCommand::Detach);
+        }
+    }
+}
+
+impl<S> TradingClient<S>
+where
+    S: Signer + Send + Sync + Clone + 'static,
+{
+    /// Spawns a dead-man's switch: beats every `config.interval` and calls `cancel_all` after
+    /// `config.max_missed` consecutive failures or on a graceful stop.
+    #[must_use = "dropping the handle stops the heartbeat, call `detach` to keep it running"]
+    pub fn start_heartbeat(&self, config: HeartbeatConfig) -> HeartbeatHandle {
+        let (commands_tx, commands) = oneshot::channel();
+        let (events_tx, events) = mpsc::channel(HEARTBEAT_EVENT_CAPACITY);
+        let task = tokio::spawn(run_heartbeat(self.clone(), config, commands, events_tx));
+
+        HeartbeatHandle { commands: Some(commands_tx), task, events }
+    }
+}
+
+async fn run_heartbeat<S>(
+    client: TradingClient<S>,
+    config: HeartbeatConfig,
+    mut commands: oneshot::Receiver<HeartbeatCommand>,
+    events: mpsc::Sender<HeartbeatEvent>,
+) where
+    S: Signer + Send + Sync,
+{
+    let mut ticker = interval(config.interval);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let mut heartbeat_id: Option<String> = None;
+    let mut missed = 0;
+    let mut detached = false;
+
+    let reason = loop {
+        tokio::select! {
+            command = &mut commands, if !detached => match command {
+                Ok(HeartbeatCommand::Stop) => break HeartbeatStopReason::Shutdown,
+                Ok(HeartbeatCommand::Detach) => {
+                    detached = true;
+                    continue;
+                }
+                Err(_) => break HeartbeatStopReason::Dropped,
+            },
+            _ = ticker.tick() => {}
+        }
+
+        // A beat that takes longer than the interval counts as missed
+        let beat = timeout(config.interval, client.post_heartbeat(heartbeat_id.as_deref()))
+            .await
+            .unwrap_or_else(|_| Err(Error::msg("Heartbeat timed out")));
+
+        match beat {
+            Ok(beat) => {
+                missed = 0;
+                let _ = events
+                    .try_send(HeartbeatEvent::Beat { heartbeat_id: beat.heartbeat_id.clone() });
+                heartbeat_id = Some(beat.heartbeat_id);
+            }
+            Err(e) => {
+                missed += 1;
+                let _ = events
+                    .try_send(HeartbeatEvent::Missed { consecutive: missed, error: e.to_string() });
+                if missed >= config.max_missed {
+                    break HeartbeatStopReason::MissedBeats;
+                }
+            }
+        }
+    };
+
+    let cancel = match reason {
+        HeartbeatStopReason::Shutdown => config.cancel_on_stop,
+        HeartbeatStopReason::MissedBeats => true,
+        HeartbeatStopReason::Dropped => false,
+    };
+    if cancel {
+        let event = match client.cancel_all().await {
+            Ok(_) => HeartbeatEvent::CancelledAll { reason },
+            Err(e) => HeartbeatEvent::CancelFailed { reason, error: e.to_string() },
+        };
+        let _ = events.try_send(event);
+    }
+    let _ = events.try_send(HeartbeatEvent::Stopped { reason });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Credentials, testing::serve};
+    use alloy_signer_local::PrivateKeySigner;
+    use std::sync::{Arc, Mutex};
+    use tokio::time::sleep;
+
+    type Paths = Arc<Mutex<Vec<String>>>;
+
+    /// Local stand-in for the CLOB answering heartbeats with `beat_status` and recording the
+    /// path of every request.
+    async fn stand_in(beat_status: u16) -> (String, Paths) {
+        let paths = Paths::default();
+        let recorded = paths.clone();
+        let api_base = serve(move |path, _| {
+            recorded.lock().unwrap().push(path.to_string());
+            match path {
+                "/v1/heartbeats" if beat_status == 200 => {
+                    (200, r#"{"heartbeat_id":"hb"}"#.to_string())
+                }
+                "/v1/heartbeats" => (beat_status, r#"{"error":"down"}"#.to_string()),
+                _ => (200, "{}".to_string()),
+            }
+        })
+        .await;
+        (api_base, paths)
+    }
+
+    fn client(api_base: &str) -> TradingClient {
+        let creds = Credentials::new("key".into(), "c2VjcmV0".into(), "pass".into());
+        TradingClient::new(api_base, PrivateKeySigner::random(), creds).unwrap()
+    }
+
+    fn config(max_missed: u32) -> HeartbeatConfig {
+        HeartbeatConfig { interval: Duration::from_millis(100), max_missed, cancel_on_stop: true }
+    }
+
+    fn count(paths: &Paths, path: &str) -> usize {
+        paths.lock().unwrap().iter().filter(|p| *p == path).count()
+    }
+
+    #[tokio::test]
+    async fn stop_cancels_all_orders() {
+        let (api_base, paths) = stand_in(200).await;
+        let mut handle = client(&api_base).start_heartbeat(config(3));
+
+        assert!(matches!(handle.next_event().await, Some(HeartbeatEvent::Beat { .. })));
+        handle.stop().await.unwrap();
+
+        assert_eq!(count(&paths, "/cancel-all"), 1);
+    }
+
+    #[tokio::test]
+    async fn missed_beats_cancel_all_orders() {
+        let (api_base, paths) = stand_in(500).await;
+        let mut handle = client(&api_base).start_heartbeat(config(2));
+
+        let mut events = Vec::new();
+        while let Some(event) = handle.next_event().await {
+            events.push(event);
+        }
+
+        assert!(matches!(events[0], HeartbeatEvent::Missed { consecutive: 1, .. }));
+        assert!(matches!(events[1], HeartbeatEvent::Missed { consecutive: 2, .. }));
+        assert!(matches!(
+            events[2],
+            HeartbeatEvent::CancelledAll { reason: HeartbeatStopReason::MissedBeats }
+        ));
+        assert!(matches!(
+            events[3],
+            HeartbeatEvent::Stopped { reason: HeartbeatStopReason::MissedBeats }
+        ));
+        assert_eq!(count(&paths, "/cancel-all"), 1);
+    }
+
+    #[tokio::test]
+    async fn dropped_handle_stops_without_cancelling() {
+        let (api_base, paths) = stand_in(200).await;
+        let mut handle = client(&api_base).start_heartbeat(config(3));
+
+        assert!(matches!(handle.next_event().await, Some(HeartbeatEvent::Beat { .. })));
+        drop(handle);
+        sleep(Duration::from_millis(50)).await;
+        let beats = count(&paths, "/v1/heartbeats");
+        sleep(Duration::from_millis(350)).await;
+
+        assert_eq!(count(&paths, "/v1/heartbeats"), beats);
+        assert_eq!(count(&paths, "/cancel-all"), 0);
+    }
+
+    #[tokio::test]
+    async fn detached_handle_keeps_beating_without_cancelling() {
+        let (api_base, paths) = stand_in(200).await;
+        let mut handle = client(&api_base).start_heartbeat(config(3));
+
+        assert!(matches!(handle.next_event().await, Some(HeartbeatEvent::Beat { .. })));
+        handle.detach();
+        let beats = count(&paths, "/v1/heartbeats");
+        sleep(Duration::from_millis(350)).await;
+
+        assert!(count(&paths, "/v1/heartbeats") > beats);
+        assert_eq!(count(&paths, "/cancel-all"), 0);
+    }
+}
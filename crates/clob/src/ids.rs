@@ -0,0 +1,180 @@
+use crate::deserialize_string_or_number;
+use alloy_primitives::{B256, U256};
+use anyhow::{Error, Result};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+use std::{borrow::Borrow, fmt, str::FromStr};
+
+/// ERC-1155 position id of an outcome token, sent by the API as a decimal string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct TokenId(pub U256);
+
+impl fmt::Display for TokenId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl FromStr for TokenId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        U256::from_str_radix(s, 10)
+            .map(Self)
+            .map_err(|e| Error::msg(format!("Invalid token id '{s}': {e}")))
+    }
+}
+
+impl From<U256> for TokenId {
+    fn from(id: U256) -> Self {
+        Self(id)
+    }
+}
+
+impl From<TokenId> for U256 {
+    fn from(id: TokenId) -> Self {
+        id.0
+    }
+}
+
+impl Serialize for TokenId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for TokenId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = deserialize_string_or_number(deserializer)?;
+        raw.parse().map_err(de::Error::custom)
+    }
+}
+
+/// Declares a `0x`-prefixed 32-byte identifier.
+macro_rules! b256_id {
+    ($(#[$meta:meta])* $name:ident, $label:literal) => {
+        $(#[$meta])*
+        #[derive(
+            Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Serialize, Deserialize,
+        )]
+        #[serde(transparent)]
+        pub struct $name(pub B256);
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self> {
+                B256::from_str(s)
+                    .map(Self)
+                    .map_err(|e| Error::msg(format!("Invalid {} '{s}': {e}", $label)))
+            }
+        }
+
+        impl From<B256> for $name {
+            fn from(id: B256) -> Self {
+                Self(id)
+            }
+        }
+
+        impl From<$name> for B256 {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+    };
+}
+
+b256_id!(
+    /// Conditional Tokens condition a market's outcome tokens are split from.
+    ConditionId,
+    "condition id"
+);
+
+b256_id!(
+    /// Hash identifying an order on the CLOB.
+    OrderId,
+    "order id"
+);
+
+/// Human-readable market identifier used in Gamma URLs.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct MarketSlug(pub String);
+
+impl MarketSlug {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for MarketSlug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for MarketSlug {
+    fn from(slug: &str) -> Self {
+        Self(slug.to_string())
+    }
+}
+
+impl From<String> for MarketSlug {
+    fn from(slug: String) -> Self {
+        Self(slug)
+    }
+}
+
+impl Borrow<str> for MarketSlug {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOKEN: &str =
+        "53135072462907880191400140706440867753044989936304433583131786753949599718775";
+    const CONDITION: &str = "0xe3b423dfad8c22ff75c9899c4e8176f628cf4ad4caa00481764d320e7415f7a9";
+
+    #[test]
+    fn token_id_round_trips_as_decimal_string() {
+        assert_eq!(TOKEN.len(), 77);
+        let json = format!("\"{TOKEN}\"");
+        let id: TokenId = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(id.to_string(), TOKEN);
+        assert_eq!(serde_json::to_string(&id).unwrap(), json);
+        assert_eq!(serde_json::from_str::<TokenId>("123").unwrap(), TokenId(U256::from(123)));
+        assert!(serde_json::from_str::<TokenId>("\"0x12\"").is_err());
+    }
+
+    #[test]
+    fn b256_ids_round_trip_as_hex() {
+        let json = format!("\"{CONDITION}\"");
+        let condition_id: ConditionId = serde_json::from_str(&json).unwrap();
+        assert_eq!(condition_id.to_string(), CONDITION);
+        assert_eq!(serde_json::to_string(&condition_id).unwrap(), json);
+        assert_eq!(CONDITION.parse::<ConditionId>().unwrap(), condition_id);
+
+        let order_id: OrderId = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&order_id).unwrap(), json);
+        assert!("0x1234".parse::<OrderId>().is_err());
+        assert!(serde_json::from_str::<OrderId>("\"0x1234\"").is_err());
+    }
+
+    #[test]
+    fn market_slug_round_trips_as_plain_string() {
+        let slug: MarketSlug = serde_json::from_str("\"will-it-rain\"").unwrap();
+        assert_eq!(slug, MarketSlug::from("will-it-rain"));
+        assert_eq!(slug.as_str(), "will-it-rain");
+        assert_eq!(serde_json::to_string(&slug).unwrap(), "\"will-it-rain\"");
+    }
+}
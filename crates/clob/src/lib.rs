@@ -1,11 +1,39 @@
+mod approvals;
 mod authenticated;
+mod balance;
+mod clock;
 mod contracts;
+mod ctf;
+mod heartbeat;
+mod ids;
+mod notifications;
 mod order;
+mod remote;
+mod rewards;
+mod scoring;
+mod store;
+#[cfg(test)]
+mod testing;
+mod tick;
 mod trading;
 mod utils;
+mod wallet;
 
+pub use approvals::*;
 pub use authenticated::*;
+pub use balance::*;
+pub use clock::*;
 pub use contracts::*;
+pub use ctf::*;
+pub use heartbeat::*;
+pub use ids::*;
+pub use notifications::*;
 pub use order::*;
+pub use remote::*;
+pub use rewards::*;
+pub use scoring::*;
+pub use store::*;
+pub use tick::*;
 pub use trading::*;
 pub use utils::*;
+pub use wallet::*;
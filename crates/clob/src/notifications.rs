@@ -0,0 +1,85 @@
+use crate::deserialize_string_or_number;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(from = "u8")]
+pub enum NotificationKind {
+    /// An order was cancelled (by the user, the operator or a market close)
+    OrderCancelled,
+    /// An order was fully or partially filled
+    OrderFilled,
+    /// A market the user traded was resolved
+    MarketResolved,
+    /// Any kind this client does not know yet
+    Other(u8),
+}
+
+impl From<u8> for NotificationKind {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::OrderCancelled,
+            2 => Self::OrderFilled,
+            4 => Self::MarketResolved,
+            other => Self::Other(other),
+        }
+    }
+}
+
+/// Notification from `/notifications`; the `payload` shape depends on `kind`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Notification {
+    #[serde(deserialize_with = "deserialize_string_or_number")]
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: NotificationKind,
+    #[serde(default)]
+    pub owner: String,
+    #[serde(default)]
+    pub payload: serde_json::Value,
+    #[serde(default)]
+    pub timestamp: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NotificationsQuery {
+    pub signature_type: u8,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DropNotificationsQuery {
+    /// Comma-separated notification IDs
+    pub ids: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_notifications() {
+        let json = r#"[
+            {"id": 17, "type": 1, "owner": "key", "payload": {"order_id": "0x01"}, "timestamp": 1700000000},
+            {"id": "18", "type": 2, "payload": {"matched_size": "5"}},
+            {"id": 19, "type": 4},
+            {"id": 20, "type": 9}
+        ]"#;
+        let notifications: Vec<Notification> = serde_json::from_str(json).unwrap();
+
+        let ids: Vec<&str> = notifications.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, ["17", "18", "19", "20"]);
+        let kinds: Vec<NotificationKind> = notifications.iter().map(|n| n.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                NotificationKind::OrderCancelled,
+                NotificationKind::OrderFilled,
+                NotificationKind::MarketResolved,
+                NotificationKind::Other(9),
+            ]
+        );
+        assert_eq!(notifications[0].payload["order_id"], "0x01");
+        assert_eq!(notifications[0].timestamp, Some(1_700_000_000));
+        assert!(notifications[2].payload.is_null());
+        assert_eq!(notifications[3].timestamp, None);
+    }
+}
@@ -1,50 +1,69 @@
-use crate::{Order, OrderKind, SignatureType, SignedOrderRequest, TOKEN_SCALE, generate_seed};
-use crate::{OrderSide, RoundConfig, TickSize};
+use crate::{
+    Order, OrderKind, SignatureType, SignedOrderRequest, TOKEN_SCALE, TokenId, generate_seed,
+};
+use crate::{OrderSide, Price, RoundConfig, TickSize, derive_funder};
 use crate::{POLYGON_EXCHANGE_CONTRACT, POLYGON_NEG_RISK_EXCHANGE_CONTRACT, sign_order_message};
 use alloy_primitives::{Address, U256};
+use alloy_signer::Signer;
 use alloy_signer_local::PrivateKeySigner;
 use anyhow::{Error, Result};
 use rust_decimal::Decimal;
 use rust_decimal::RoundingStrategy::AwayFromZero;
 use rust_decimal::RoundingStrategy::MidpointTowardZero;
 use rust_decimal::RoundingStrategy::ToZero;
-use std::str::FromStr;
 
-pub struct OrderParams {
-    pub token_id: String,
-    pub price: Decimal,
+pub struct OrderParams<S = PrivateKeySigner> {
+    pub token_id: TokenId,
+    /// Limit price, carrying the market's tick size
+    pub price: Price,
     pub side: OrderSide,
     pub nonce: Option<U256>,
+    /// Explicit order salt, e.g. from a seeded `SaltGenerator`; defaults to `generate_seed()`
+    pub salt: Option<u64>,
     pub fee_rate_bps: Option<u32>,
     pub expiration: Option<u64>,
     pub taker: Option<Address>,
     pub signer: Address,
     pub funder: Option<Address>,
-    pub tick_size: String,
     pub kind: OrderKind,
     pub sig_type: SignatureType,
     pub neg_risk: bool,
-    pub wallet: PrivateKeySigner,
+    pub wallet: S,
 }
 
 // Automatically sign
 // Automatically select the optimal price from the incoming order book to create an order
-pub async fn create_order(params: OrderParams) -> Result<SignedOrderRequest, Error> {
+pub async fn create_order<S>(params: OrderParams<S>) -> Result<SignedOrderRequest, Error>
+where
+    S: Signer + Send + Sync,
+{
     let signer = params.wallet.address();
     let nonce = params.nonce.unwrap_or(U256::ZERO);
     let fee_rate_bps = params.fee_rate_bps.unwrap_or(0_u32);
     let expiration = params.expiration.unwrap_or(0_u64);
     let taker = params.taker.unwrap_or(Address::ZERO);
-    let funder = params.funder.unwrap_or(signer);
-    let tick_size = TickSize::from_str(&params.tick_size).map_err(|e| Error::msg(e))?;
-
-    let (maker_amount, taker_amount) =
-        calculate_order_amounts(params.price, params.side, params.kind, tick_size);
+    // Proxy and Safe wallets hold the funds, so the maker defaults to the derived wallet
+    let funder = match (params.sig_type, params.funder) {
+        (SignatureType::Eoa, Some(funder)) if funder != signer => {
+            return Err(Error::msg(format!(
+                "EOA orders must be funded by the signer {signer}, got {funder}"
+            )));
+        }
+        (_, Some(funder)) => funder,
+        (sig_type, None) => derive_funder(signer, sig_type),
+    };
 
-    let seed = generate_seed()?;
+    let (maker_amount, taker_amount) = calculate_order_amounts(
+        params.price.value(),
+        params.side,
+        params.kind,
+        params.price.tick_size(),
+    );
 
-    let u256_token_id = U256::from_str_radix(&params.token_id, 10)
-        .map_err(|e| Error::msg(format!("Invalid token_id: {}", e)))?;
+    let seed = match params.salt {
+        Some(salt) => salt,
+        None => generate_seed()?,
+    };
 
     let salt = U256::from(seed);
 
@@ -53,7 +72,7 @@ pub async fn create_order(params: OrderParams) -> Result<SignedOrderRequest, Err
         maker: funder,
         signer,
         taker,
-        tokenId: u256_token_id,
+        tokenId: params.token_id.into(),
         makerAmount: U256::from(maker_amount),
         takerAmount: U256::from(taker_amount),
         expiration: U256::from(expiration),
@@ -67,7 +86,7 @@ pub async fn create_order(params: OrderParams) -> Result<SignedOrderRequest, Err
         true => POLYGON_NEG_RISK_EXCHANGE_CONTRACT,
         false => POLYGON_EXCHANGE_CONTRACT,
     };
-    let signature = sign_order_message(&params.wallet, order, exchange_contract)?;
+    let signature = sign_order_message(&params.wallet, order, exchange_contract).await?;
 
     Ok(SignedOrderRequest {
         salt: seed,
@@ -118,7 +137,7 @@ pub fn calculate_order_amounts(
         // User specifies exact base size (outcome shares) they want to buy.
         // maker = USDC (quote), taker = shares (base)
         (OrderKind::Limit { size }, OrderSide::Buy) => {
-            let raw_taker_amt = size.round_dp_with_strategy(round_cfg.size, ToZero); // base shares
+            let raw_taker_amt = size.value().round_dp_with_strategy(round_cfg.size, ToZero); // base shares
             let raw_maker_amt = fix_amount_rounding(raw_taker_amt * raw_price, &round_cfg); // USDC
 
             (
@@ -131,7 +150,7 @@ pub fn calculate_order_amounts(
         // User specifies exact base size (outcome shares) they want to sell.
         // maker = shares (base), taker = USDC (quote)
         (OrderKind::Limit { size }, OrderSide::Sell) => {
-            let raw_maker_amt = size.round_dp_with_strategy(round_cfg.size, ToZero); // base shares
+            let raw_maker_amt = size.value().round_dp_with_strategy(round_cfg.size, ToZero); // base shares
             let raw_taker_amt = fix_amount_rounding(raw_maker_amt * raw_price, &round_cfg); // USDC
 
             (
@@ -157,7 +176,7 @@ pub fn calculate_order_amounts(
         // User specifies exact amount of outcome shares they want to sell.
         // maker = shares (base), taker = USDC (quote)
         (OrderKind::MarketSell { base_amount }, OrderSide::Sell) => {
-            let raw_base = base_amount.round_dp_with_strategy(round_cfg.size, ToZero); // shares
+            let raw_base = base_amount.value().round_dp_with_strategy(round_cfg.size, ToZero); // shares
             let raw_quote = fix_amount_rounding(raw_base * raw_price, &round_cfg); // USDC
 
             (
@@ -191,3 +210,103 @@ fn decimal_to_token_u32(amt: Decimal) -> u32 {
     }
     scaled.try_into().expect("Couldn't round decimal to u32 token units")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Size, derive_proxy_wallet, derive_safe_wallet, order_domain};
+    use alloy_primitives::B256;
+    use alloy_sol_types::SolStruct;
+    use std::str::FromStr;
+
+    fn params(wallet: PrivateKeySigner) -> OrderParams {
+        OrderParams {
+            token_id: TokenId(U256::from(1234)),
+            price: Price::new(Decimal::new(5, 1), TickSize::Hundredth).unwrap(),
+            side: OrderSide::Buy,
+            nonce: None,
+            salt: None,
+            fee_rate_bps: None,
+            expiration: None,
+            taker: None,
+            signer: wallet.address(),
+            funder: None,
+            kind: OrderKind::Limit { size: Size::new(Decimal::from(10)).unwrap() },
+            sig_type: SignatureType::Eoa,
+            neg_risk: false,
+            wallet,
+        }
+    }
+
+    fn order_hash(order: &SignedOrderRequest) -> B256 {
+        let amount = |raw: &str| U256::from_str(raw).unwrap();
+        Order {
+            salt: U256::from(order.salt),
+            maker: order.maker.parse().unwrap(),
+            signer: order.signer.parse().unwrap(),
+            taker: order.taker.parse().unwrap(),
+            tokenId: order.token_id.into(),
+            makerAmount: amount(&order.maker_amount),
+            takerAmount: amount(&order.taker_amount),
+            expiration: amount(&order.expiration),
+            nonce: amount(&order.nonce),
+            feeRateBps: amount(&order.fee_rate_bps),
+            side: order.side.to_u8(),
+            signatureType: order.signature_type,
+        }
+        .eip712_signing_hash(&order_domain(POLYGON_EXCHANGE_CONTRACT))
+    }
+
+    fn maker(order: &SignedOrderRequest) -> Address {
+        order.maker.parse().unwrap()
+    }
+
+    #[tokio::test]
+    async fn eoa_order_rejects_foreign_funder() {
+        let mut params = params(PrivateKeySigner::random());
+        params.funder = Some(Address::repeat_byte(0x33));
+        assert!(create_order(params).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn non_eoa_order_derives_funder() {
+        let wallet = PrivateKeySigner::random();
+        let signer = wallet.address();
+
+        let mut proxy = params(wallet.clone());
+        proxy.sig_type = SignatureType::PolyProxy;
+        let order = create_order(proxy).await.unwrap();
+        assert_eq!(maker(&order), derive_proxy_wallet(signer));
+        assert_eq!(order.signer.parse::<Address>().unwrap(), signer);
+
+        let mut safe = params(wallet);
+        safe.sig_type = SignatureType::PolyGnosisSafe;
+        assert_eq!(maker(&create_order(safe).await.unwrap()), derive_safe_wallet(signer));
+    }
+
+    #[tokio::test]
+    async fn explicit_funder_is_kept() {
+        let wallet = PrivateKeySigner::random();
+        let signer = wallet.address();
+
+        let mut safe = params(wallet.clone());
+        safe.sig_type = SignatureType::PolyGnosisSafe;
+        safe.funder = Some(Address::repeat_byte(0x33));
+        assert_eq!(maker(&create_order(safe).await.unwrap()), Address::repeat_byte(0x33));
+
+        let mut eoa = params(wallet);
+        eoa.funder = Some(signer);
+        assert_eq!(maker(&create_order(eoa).await.unwrap()), signer);
+    }
+
+    #[tokio::test]
+    async fn identical_orders_get_distinct_salts_and_hashes() {
+        let wallet = PrivateKeySigner::random();
+        let first = create_order(params(wallet.clone())).await.unwrap();
+        let second = create_order(params(wallet)).await.unwrap();
+
+        assert_ne!(first.salt, second.salt);
+        assert_ne!(order_hash(&first), order_hash(&second));
+        assert_ne!(first.signature, second.signature);
+    }
+}
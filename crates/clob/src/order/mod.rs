@@ -1,7 +1,11 @@
 mod create;
+mod price;
+mod salt;
 mod sign;
 mod types;
 
 pub use create::*;
+pub use price::*;
+pub use salt::*;
 pub use sign::*;
 pub use types::*;
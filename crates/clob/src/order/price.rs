@@ -0,0 +1,228 @@
+use crate::{OrderSide, TickSize, deserialize_decimal};
+use anyhow::{Error, Result, bail};
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{cmp::Ordering, fmt};
+
+/// Decimal places of order sizes, the same for every tick size.
+pub const SIZE_DECIMALS: u32 = 2;
+
+/// Price of an outcome token, aligned to its market's tick size and inside `[tick, 1 - tick]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Price {
+    value: Decimal,
+    tick_size: TickSize,
+}
+
+impl Price {
+    /// Validated price, erroring when `value` is off-tick or outside `[tick, 1 - tick]`.
+    pub fn new(value: Decimal, tick_size: TickSize) -> Result<Self> {
+        let tick = tick_size.as_decimal();
+        if value < tick || value > Decimal::ONE - tick {
+            bail!("Price {value} is outside [{tick}, {}]", Decimal::ONE - tick);
+        }
+        if !(value % tick).is_zero() {
+            bail!("Price {value} is not a multiple of the tick size {tick}");
+        }
+        Ok(Self { value: value.normalize(), tick_size })
+    }
+
+    /// Price nearest to `value` on the tick grid, half-ticks rounding toward zero.
+    pub fn round(value: Decimal, tick_size: TickSize) -> Result<Self> {
+        let dp = tick_size.decimals();
+        Self::new(value.round_dp_with_strategy(dp, RoundingStrategy::MidpointTowardZero), tick_size)
+    }
+
+    pub const fn value(self) -> Decimal {
+        self.value
+    }
+
+    pub const fn tick_size(self) -> TickSize {
+        self.tick_size
+    }
+
+    /// Same price on a market with a different tick size, e.g. after a tick size change.
+    pub fn with_tick_size(self, tick_size: TickSize) -> Result<Self> {
+        Self::new(self.value, tick_size)
+    }
+
+    /// Price `n` ticks higher, `None` past `1 - tick`.
+    pub fn up(self, n: u32) -> Option<Self> {
+        let value = self.value + self.tick_size.as_decimal() * Decimal::from(n);
+        Self::new(value, self.tick_size).ok()
+    }
+
+    /// Price `n` ticks lower, `None` below one tick.
+    pub fn down(self, n: u32) -> Option<Self> {
+        let value = self.value - self.tick_size.as_decimal() * Decimal::from(n);
+        Self::new(value, self.tick_size).ok()
+    }
+
+    /// This price moved onto the `tick_size` grid without becoming more aggressive: bids round
+    /// down and asks round up. `None` when that leaves the valid range.
+    pub fn reprice(self, tick_size: TickSize, side: OrderSide) -> Option<Self> {
+        let strategy = match side {
+            OrderSide::Buy => RoundingStrategy::ToNegativeInfinity,
+            OrderSide::Sell => RoundingStrategy::ToPositiveInfinity,
+        };
+        let value = self.value.round_dp_with_strategy(tick_size.decimals(), strategy);
+        Self::new(value, tick_size).ok()
+    }
+
+    /// Price of the opposite outcome, `1 - p`: a bid at `p` on one token is an ask at `1 - p`
+    /// on the other.
+    pub fn complement(self) -> Self {
+        // The valid range is symmetric around 0.5, so the complement is always valid
+        Self { value: (Decimal::ONE - self.value).normalize(), tick_size: self.tick_size }
+    }
+}
+
+impl PartialOrd for Price {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Price {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let precision = |price: &Self| price.tick_size.decimals();
+        self.value.cmp(&other.value).then_with(|| precision(self).cmp(&precision(other)))
+    }
+}
+
+impl fmt::Display for Price {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+impl From<Price> for Decimal {
+    fn from(price: Price) -> Self {
+        price.value
+    }
+}
+
+impl Serialize for Price {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.value)
+    }
+}
+
+/// Positive order size in outcome shares, with at most [`SIZE_DECIMALS`] decimals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Size(Decimal);
+
+impl Size {
+    pub fn new(value: Decimal) -> Result<Self> {
+        if value <= Decimal::ZERO {
+            bail!("Size {value} must be positive");
+        }
+        let value = value.normalize();
+        if value.scale() > SIZE_DECIMALS {
+            bail!("Size {value} has more than {SIZE_DECIMALS} decimals");
+        }
+        Ok(Self(value))
+    }
+
+    /// Largest valid size not above `value`.
+    pub fn round_down(value: Decimal) -> Result<Self> {
+        Self::new(value.round_dp_with_strategy(SIZE_DECIMALS, RoundingStrategy::ToZero))
+    }
+
+    pub const fn value(self) -> Decimal {
+        self.0
+    }
+
+    /// USDC value of this many shares at `price`.
+    pub fn notional(self, price: Price) -> Decimal {
+        self.0 * price.value
+    }
+}
+
+impl fmt::Display for Size {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl From<Size> for Decimal {
+    fn from(size: Size) -> Self {
+        size.0
+    }
+}
+
+impl Serialize for Size {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Size {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = deserialize_decimal(deserializer)?;
+        Self::new(value).map_err(|e: Error| serde::de::Error::custom(e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    fn price(value: &str, tick_size: TickSize) -> Price {
+        Price::new(dec(value), tick_size).unwrap()
+    }
+
+    #[test]
+    fn new_rejects_off_tick_and_out_of_range() {
+        assert!(Price::new(dec("0.015"), TickSize::Hundredth).is_err());
+        assert!(Price::new(dec("0.001"), TickSize::Hundredth).is_err());
+        assert!(Price::new(dec("1"), TickSize::Hundredth).is_err());
+        assert!(Price::new(dec("0.01"), TickSize::Hundredth).is_ok());
+        assert!(Price::new(dec("0.99"), TickSize::Hundredth).is_ok());
+    }
+
+    #[test]
+    fn up_and_down_stop_at_range_edges() {
+        let top = price("0.99", TickSize::Hundredth);
+        assert_eq!(top.up(1), None);
+        assert_eq!(top.down(1), Some(price("0.98", TickSize::Hundredth)));
+
+        let bottom = price("0.01", TickSize::Hundredth);
+        assert_eq!(bottom.down(1), None);
+        assert_eq!(bottom.up(3), Some(price("0.04", TickSize::Hundredth)));
+
+        assert_eq!(price("0.5", TickSize::Tenth).up(4), Some(price("0.9", TickSize::Tenth)));
+        assert_eq!(price("0.5", TickSize::Tenth).up(5), None);
+    }
+
+    #[test]
+    fn complement_mirrors_around_one() {
+        assert_eq!(price("0.37", TickSize::Hundredth).complement().value(), dec("0.63"));
+        assert_eq!(price("0.001", TickSize::Thousandth).complement().value(), dec("0.999"));
+        assert_eq!(price("0.5", TickSize::Tenth).complement(), price("0.5", TickSize::Tenth));
+    }
+
+    #[test]
+    fn round_sends_half_ticks_toward_zero() {
+        assert_eq!(Price::round(dec("0.125"), TickSize::Hundredth).unwrap().value(), dec("0.12"));
+        assert_eq!(Price::round(dec("0.1251"), TickSize::Hundredth).unwrap().value(), dec("0.13"));
+        assert_eq!(Price::round(dec("0.55"), TickSize::Tenth).unwrap().value(), dec("0.5"));
+        assert!(Price::round(dec("0.004"), TickSize::Hundredth).is_err());
+    }
+
+    #[test]
+    fn size_limits_decimals_and_sign() {
+        assert!(Size::new(dec("1.005")).is_err());
+        assert!(Size::new(Decimal::ZERO).is_err());
+        assert_eq!(Size::round_down(dec("1.009")).unwrap().value(), dec("1.00"));
+        assert_eq!(
+            Size::new(dec("10")).unwrap().notional(price("0.25", TickSize::Hundredth)),
+            dec("2.5")
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use std::{
+    sync::{LazyLock, Mutex},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Largest salt emitted, kept within the JSON-safe integer range (2^53 - 1) because the API
+/// reads `salt` as a JSON number.
+pub const MAX_ORDER_SALT: u64 = (1 << 53) - 1;
+
+/// Number of low salt bits filled with randomness, the remaining high bits hold Unix millis.
+const RANDOM_SALT_BITS: u32 = 10;
+
+static DEFAULT_SALT_GENERATOR: LazyLock<SaltGenerator> = LazyLock::new(SaltGenerator::new);
+
+/// How a [`SaltGenerator`] fills the salt bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SaltMode {
+    /// Unix milliseconds in the high bits and 10 random low bits, strictly increasing
+    Timestamped,
+    /// All 53 bits random, never repeating the previous salt; depends only on the RNG
+    #[default]
+    Random,
+}
+
+/// Source of order salts, giving two otherwise identical orders different order hashes.
+///
+/// Timestamped salts only leave 10 random bits per millisecond, so processes sharing a key may
+/// collide; the default and process-wide generators are [`SaltMode::Random`]. A
+/// [`SaltGenerator::random_with_rng`] generator with a seeded RNG gives reproducible salts.
+#[derive(Debug)]
+pub struct SaltGenerator<R = StdRng> {
+    mode: SaltMode,
+    state: Mutex<SaltState<R>>,
+}
+
+#[derive(Debug)]
+struct SaltState<R> {
+    rng: R,
+    last: u64,
+}
+
+impl SaltGenerator {
+    /// Fully random generator seeded from OS entropy.
+    pub fn new() -> Self {
+        Self::with_mode(StdRng::from_os_rng(), SaltMode::default())
+    }
+
+    /// The process-wide generator used when an order has no explicit salt.
+    pub fn global() -> &'static Self {
+        &DEFAULT_SALT_GENERATOR
+    }
+}
+
+impl Default for SaltGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: Rng> SaltGenerator<R> {
+    /// Timestamped generator drawing its random bits from `rng`.
+    pub const fn with_rng(rng: R) -> Self {
+        Self::with_mode(rng, SaltMode::Timestamped)
+    }
+
+    /// Fully random generator drawing every bit from `rng`.
+    pub const fn random_with_rng(rng: R) -> Self {
+        Self::with_mode(rng, SaltMode::Random)
+    }
+
+    pub const fn with_mode(rng: R, mode: SaltMode) -> Self {
+        Self { mode, state: Mutex::new(SaltState { rng, last: 0 }) }
+    }
+
+    pub const fn mode(&self) -> SaltMode {
+        self.mode
+    }
+
+    pub fn next_salt(&self) -> u64 {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        match self.mode {
+            SaltMode::Timestamped => state.next_timestamped(),
+            SaltMode::Random => state.next_random(),
+        }
+    }
+}
+
+impl<R: Rng> SaltState<R> {
+    fn next_timestamped(&mut self) -> u64 {
+        let millis =
+            SystemTime::now().duration_since(UNIX_EPOCH).expect("time went backwards").as_millis()
+                as u64;
+        let random = self.rng.random::<u64>() & ((1 << RANDOM_SALT_BITS) - 1);
+        let candidate = ((millis << RANDOM_SALT_BITS) | random) & MAX_ORDER_SALT;
+
+        let salt = if candidate > self.last { candidate } else { self.last + 1 };
+        self.last = salt;
+        salt
+    }
+
+    fn next_random(&mut self) -> u64 {
+        loop {
+            let salt = self.rng.random::<u64>() & MAX_ORDER_SALT;
+            if salt != self.last {
+                self.last = salt;
+                return salt;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeded_random_salts_are_reproducible() {
+        let a = SaltGenerator::random_with_rng(StdRng::seed_from_u64(7));
+        let b = SaltGenerator::random_with_rng(StdRng::seed_from_u64(7));
+        let salts: Vec<u64> = (0..8).map(|_| a.next_salt()).collect();
+
+        assert_eq!(salts, (0..8).map(|_| b.next_salt()).collect::<Vec<_>>());
+        assert!(salts.iter().all(|&salt| salt <= MAX_ORDER_SALT));
+        // Salts use the high bits too, not just a low random window
+        assert!(salts.iter().any(|&salt| salt >= 1 << 52));
+    }
+
+    #[test]
+    fn timestamped_salts_strictly_increase() {
+        let generator = SaltGenerator::with_rng(StdRng::seed_from_u64(7));
+        let salts: Vec<u64> = (0..1000).map(|_| generator.next_salt()).collect();
+
+        assert!(salts.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(salts.iter().all(|&salt| salt <= MAX_ORDER_SALT));
+    }
+}
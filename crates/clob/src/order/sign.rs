@@ -1,11 +1,8 @@
-use crate::{POLYGON_MAINNET_CHAIN_ID, get_current_unix_time_secs};
+use crate::{POLYGON_MAINNET_CHAIN_ID, SaltGenerator};
 use alloy_primitives::{Address, hex::encode_prefixed};
-use alloy_signer::SignerSync;
-use alloy_signer_local::PrivateKeySigner;
-use alloy_sol_types::SolStruct;
-use alloy_sol_types::{eip712_domain, sol};
+use alloy_signer::Signer;
+use alloy_sol_types::{Eip712Domain, eip712_domain, sol};
 use anyhow::{Error, Result};
-use rand::{Rng, rng};
 
 sol! {
     struct Order {
@@ -24,29 +21,33 @@ sol! {
     }
 }
 
+/// Next salt from the process-wide [`SaltGenerator`].
 pub fn generate_seed() -> Result<u64> {
-    let mut rng = rng();
-    let y: f64 = rng.random();
-    let timestamp = get_current_unix_time_secs();
-    let a: f64 = timestamp as f64 * y;
-    Ok(a as u64)
+    Ok(SaltGenerator::global().next_salt())
 }
 
-pub fn sign_order_message(
-    signer: &PrivateKeySigner,
-    order: Order,
-    verifying_contract: Address,
-) -> Result<String> {
-    let domain = eip712_domain!(
+/// EIP-712 domain of orders verified by the exchange at `verifying_contract`.
+pub fn order_domain(verifying_contract: Address) -> Eip712Domain {
+    eip712_domain!(
         name: "Polymarket CTF Exchange",
         version: "1",
         chain_id: POLYGON_MAINNET_CHAIN_ID,
         verifying_contract: verifying_contract,
-    );
+    )
+}
 
-    let hash = order.eip712_signing_hash(&domain);
+pub async fn sign_order_message<S>(
+    signer: &S,
+    order: Order,
+    verifying_contract: Address,
+) -> Result<String>
+where
+    S: Signer + Send + Sync,
+{
+    let domain = order_domain(verifying_contract);
     let signature = signer
-        .sign_hash_sync(&hash)
+        .sign_typed_data(&order, &domain)
+        .await
         .map_err(|e| Error::msg(format!("Failed to sign order: {e}")))?;
 
     Ok(encode_prefixed(signature.as_bytes()))
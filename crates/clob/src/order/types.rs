@@ -1,7 +1,8 @@
-use std::str::FromStr;
+use std::{fmt, str::FromStr};
 
+use crate::{Size, TokenId, deserialize_decimal};
 use rust_decimal::Decimal;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
@@ -42,6 +43,7 @@ pub enum TickSize {
 }
 
 impl TickSize {
+    #[deprecated(note = "floats cannot represent ticks exactly, use `as_decimal`")]
     pub fn as_f64(self) -> f64 {
         match self {
             Self::Tenth => 0.1,
@@ -51,6 +53,31 @@ impl TickSize {
         }
     }
 
+    /// The tick as an exact decimal, e.g. `0.01`
+    pub const fn as_decimal(self) -> Decimal {
+        Decimal::from_parts(1, 0, 0, false, self.decimals())
+    }
+
+    /// Decimal places of a price on this tick
+    pub const fn decimals(self) -> u32 {
+        match self {
+            Self::Tenth => 1,
+            Self::Hundredth => 2,
+            Self::Thousandth => 3,
+            Self::TenThousandth => 4,
+        }
+    }
+
+    /// Lowest valid price, one tick
+    pub const fn min_price(self) -> Decimal {
+        self.as_decimal()
+    }
+
+    /// Highest valid price, one tick below 1
+    pub fn max_price(self) -> Decimal {
+        Decimal::ONE - self.as_decimal()
+    }
+
     pub fn round_config(self) -> RoundConfig {
         match self {
             TickSize::Tenth => RoundConfig { price: 1, size: 2, amount: 3 },
@@ -61,17 +88,43 @@ impl TickSize {
     }
 }
 
+impl TryFrom<Decimal> for TickSize {
+    type Error = &'static str;
+
+    fn try_from(value: Decimal) -> Result<Self, Self::Error> {
+        [Self::Tenth, Self::Hundredth, Self::Thousandth, Self::TenThousandth]
+            .into_iter()
+            .find(|tick| tick.as_decimal() == value)
+            .ok_or("invalid tick size")
+    }
+}
+
 impl FromStr for TickSize {
     type Err = &'static str;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "0.1" => Ok(Self::Tenth),
-            "0.01" => Ok(Self::Hundredth),
-            "0.001" => Ok(Self::Thousandth),
-            "0.0001" => Ok(Self::TenThousandth),
-            _ => Err("invalid tick size"),
-        }
+        Decimal::from_str(s).map_err(|_| "invalid tick size")?.try_into()
+    }
+}
+
+impl fmt::Display for TickSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_decimal(), f)
+    }
+}
+
+/// Serialized as a decimal string, e.g. `"0.01"`.
+impl Serialize for TickSize {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserialized from a decimal string or a JSON number.
+impl<'de> Deserialize<'de> for TickSize {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = deserialize_decimal(deserializer)?;
+        Self::try_from(value).map_err(|_| de::Error::custom(format!("Invalid tick size: {value}")))
     }
 }
 
@@ -83,7 +136,7 @@ pub enum OrderKind {
     /// Example: "Buy 500 YES shares at max $0.65 each" → size = 500
     Limit {
         /// Quantity in base token units (outcome shares / conditional tokens), not in dollars
-        size: Decimal,
+        size: Size,
     },
 
     /// Market buy order using a fixed quote (USDC) amount  
@@ -103,7 +156,7 @@ pub enum OrderKind {
     /// Example: "Sell 2500 NO shares at market price"
     MarketSell {
         /// Quantity in outcome tokens (base token units) to sell
-        base_amount: Decimal,
+        base_amount: Size,
     },
 }
 
@@ -151,7 +204,7 @@ pub struct SignedOrderRequest {
     pub signer: String,
     pub taker: String,
     #[serde(rename = "tokenId")]
-    pub token_id: String,
+    pub token_id: TokenId,
     #[serde(rename = "makerAmount")]
     pub maker_amount: String,
     #[serde(rename = "takerAmount")]
@@ -188,8 +241,8 @@ pub enum OrderType {
 
     /// Good-Til-Date  
     /// A limit order that remains active until either it is filled, cancelled, or the specified expiration timestamp (Unix seconds, UTC) is reached.  
-    /// Note: Polymarket enforces a minimum expiration buffer of 1 minute. If the desired expiration is less than 90 seconds away,  
-    /// the actual expiration must be set to `current_time + 90 seconds`.
+    /// Note: Polymarket applies a security threshold of 1 minute, so an order meant to live for 90 seconds  
+    /// must carry `current_time + 60 + 90` as its expiration. Use `ServerClock::expires_in` / `expires_at`.
     #[serde(rename = "GTD")]
     Gtd,
 }
@@ -0,0 +1,303 @@
+use crate::{
+    ClobAuth, Order, OrderSide, POLYGON_EXCHANGE_CONTRACT, POLYGON_NEG_RISK_EXCHANGE_CONTRACT,
+    TOKEN_SCALE, TokenId, into_result, order_domain,
+};
+use alloy_primitives::{Address, B256, Bytes, ChainId, Signature, U256, keccak256};
+use alloy_signer::{Signer, UnsupportedSignerOperation};
+use alloy_sol_types::{Eip712Domain, SolStruct, SolType};
+use anyhow::{Error, Result, bail};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashSet, str::FromStr};
+
+/// Domain name used for L1 `ClobAuth` signatures.
+const CLOB_AUTH_DOMAIN_NAME: &str = "ClobAuthDomain";
+
+/// Payload sent by [`RemoteSigner`] to a signing daemon (`POST /sign`).
+///
+/// `data` is the EIP-712 `encodeData` of the struct named by `primary_type`, so the daemon can
+/// decode the full `Order` and recompute the signing hash itself instead of trusting a bare hash.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteSignRequest {
+    pub primary_type: String,
+    pub domain: Eip712Domain,
+    pub data: Bytes,
+}
+
+impl RemoteSignRequest {
+    /// Request to sign `payload` under `domain`.
+    pub fn new<T: SolStruct>(payload: &T, domain: &Eip712Domain) -> Self {
+        Self {
+            primary_type: T::NAME.to_string(),
+            domain: domain.clone(),
+            data: payload.eip712_encode_data().into(),
+        }
+    }
+}
+
+/// Response of a signing daemon (`POST /sign`), the signature is `0x`-prefixed hex.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteSignResponse {
+    pub signature: String,
+}
+
+/// Response of a signing daemon (`GET /address`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteAddressResponse {
+    pub address: Address,
+}
+
+/// [`Signer`] that forwards EIP-712 `Order` and `ClobAuth` payloads to a separate signing process.
+///
+/// Only typed data can be signed; `sign_hash` and `sign_message` are refused so the daemon always
+/// sees what it is signing. Works anywhere a local wallet does, e.g. `create_order`.
+#[derive(Debug, Clone)]
+pub struct RemoteSigner {
+    api_base: String,
+    client: reqwest::Client,
+    address: Address,
+    chain_id: Option<ChainId>,
+}
+
+impl RemoteSigner {
+    /// Connects to the daemon at `api_base` and fetches the address of the key it holds.
+    pub async fn connect(api_base: &str) -> Result<Self> {
+        let client = reqwest::Client::builder().build()?;
+        let url = format!("{}/address", api_base);
+        let response = client.get(&url).send().await?;
+        let RemoteAddressResponse { address } = into_result(response).await?;
+
+        Ok(Self { api_base: api_base.to_string(), client, address, chain_id: None })
+    }
+
+    pub async fn sign_request(&self, request: &RemoteSignRequest) -> Result<Signature> {
+        let url = format!("{}/sign", self.api_base);
+        let response = self.client.post(&url).json(request).send().await?;
+        let RemoteSignResponse { signature } = into_result(response).await?;
+
+        Signature::from_str(&signature)
+            .map_err(|e| Error::msg(format!("Invalid signature from remote signer: {e}")))
+    }
+}
+
+#[async_trait::async_trait]
+impl Signer for RemoteSigner {
+    async fn sign_hash(&self, _hash: &B256) -> alloy_signer::Result<Signature> {
+        Err(alloy_signer::Error::UnsupportedOperation(UnsupportedSignerOperation::SignHash))
+    }
+
+    async fn sign_typed_data<T: SolStruct + Send + Sync>(
+        &self,
+        payload: &T,
+        domain: &Eip712Domain,
+    ) -> alloy_signer::Result<Signature>
+    where
+        Self: Sized,
+    {
+        let request = RemoteSignRequest::new(payload, domain);
+        self.sign_request(&request).await.map_err(|e| alloy_signer::Error::other(e.to_string()))
+    }
+
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn chain_id(&self) -> Option<ChainId> {
+        self.chain_id
+    }
+
+    fn set_chain_id(&mut self, chain_id: Option<ChainId>) {
+        self.chain_id = chain_id;
+    }
+}
+
+/// Rules a signing daemon enforces before signing an order.
+///
+/// Missing fields fall back to the defaults: no notional limit, any token, and only the two
+/// Polymarket exchange contracts as verifying contracts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SigningPolicy {
+    /// Maximum USDC notional of a single order
+    pub max_notional: Option<Decimal>,
+    /// Token IDs that may be traded, `None` allows all
+    pub allowed_token_ids: Option<HashSet<TokenId>>,
+    /// Exchange contracts an order may be signed for
+    pub allowed_exchanges: Vec<Address>,
+}
+
+impl Default for SigningPolicy {
+    fn default() -> Self {
+        Self {
+            max_notional: None,
+            allowed_token_ids: None,
+            allowed_exchanges: vec![POLYGON_EXCHANGE_CONTRACT, POLYGON_NEG_RISK_EXCHANGE_CONTRACT],
+        }
+    }
+}
+
+impl SigningPolicy {
+    /// Checks `request` against the policy for the key at `signer` and returns the EIP-712 hash
+    /// that may be signed.
+    pub fn authorize(&self, signer: Address, request: &RemoteSignRequest) -> Result<B256> {
+        match request.primary_type.as_str() {
+            Order::NAME => self.authorize_order(signer, request),
+            ClobAuth::NAME => authorize_clob_auth(signer, request),
+            other => bail!("Unsupported primary type: {other}"),
+        }
+    }
+
+    fn authorize_order(&self, signer: Address, request: &RemoteSignRequest) -> Result<B256> {
+        let domain = &request.domain;
+        let exchange = domain.verifying_contract.unwrap_or_default();
+        if !self.allowed_exchanges.contains(&exchange) {
+            bail!("Exchange contract {exchange} is not allowed");
+        }
+        // Name, version and chain must be exactly the exchange's, e.g. no other chain id
+        if *domain != order_domain(exchange) {
+            bail!("Unexpected order domain: {domain:?}");
+        }
+
+        let order = <Order as SolType>::abi_decode(&request.data)
+            .map_err(|e| Error::msg(format!("Invalid order payload: {e}")))?;
+        if order.eip712_encode_data() != request.data.as_ref() {
+            bail!("Order payload is not canonically encoded");
+        }
+        if order.signer != signer {
+            bail!("Order signer {} does not match {signer}", order.signer);
+        }
+
+        if let Some(allowed) = &self.allowed_token_ids {
+            let token_id = TokenId(order.tokenId);
+            if !allowed.contains(&token_id) {
+                bail!("Token {token_id} is not allowed");
+            }
+        }
+
+        if let Some(max_notional) = self.max_notional {
+            let notional = order_notional(&order)?;
+            if notional > max_notional {
+                bail!("Order notional {notional} exceeds limit {max_notional}");
+            }
+        }
+
+        Ok(order.eip712_signing_hash(domain))
+    }
+}
+
+/// Only checks the domain and that the auth is for `signer`: any timestamp, nonce and message
+/// is signed, so whoever can reach the daemon can create or derive API keys.
+fn authorize_clob_auth(signer: Address, request: &RemoteSignRequest) -> Result<B256> {
+    let domain = &request.domain;
+    if domain.name.as_deref() != Some(CLOB_AUTH_DOMAIN_NAME) {
+        bail!("Unexpected auth domain: {:?}", domain.name);
+    }
+    // address, keccak(timestamp), nonce, keccak(message)
+    if request.data.len() != 4 * 32 {
+        bail!("Invalid auth payload length: {}", request.data.len());
+    }
+    let address = Address::from_word(B256::from_slice(&request.data[..32]));
+    if address != signer {
+        bail!("Auth address {address} does not match {signer}");
+    }
+
+    let type_hash = keccak256(ClobAuth::eip712_encode_type().as_bytes());
+    let struct_hash = keccak256([type_hash.as_slice(), request.data.as_ref()].concat());
+    Ok(keccak256([&[0x19, 0x01], domain.separator().as_slice(), struct_hash.as_slice()].concat()))
+}
+
+/// USDC notional of a signed order: the maker amount for buys, the taker amount for sells.
+fn order_notional(order: &Order) -> Result<Decimal> {
+    let quote: U256 =
+        if order.side == OrderSide::Buy.to_u8() { order.makerAmount } else { order.takerAmount };
+    let quote = Decimal::from_str(&quote.to_string())
+        .map_err(|e| Error::msg(format!("Order amount out of range: {e}")))?;
+    Ok(quote / TOKEN_SCALE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_signer::SignerSync;
+    use alloy_signer_local::PrivateKeySigner;
+
+    /// Buy of 200 shares at 0.5, i.e. 100 USDC.
+    fn buy_order(signer: Address) -> Order {
+        Order {
+            salt: U256::from(42),
+            maker: signer,
+            signer,
+            taker: Address::ZERO,
+            tokenId: U256::from(1234),
+            makerAmount: U256::from(100_000_000),
+            takerAmount: U256::from(200_000_000),
+            expiration: U256::ZERO,
+            nonce: U256::ZERO,
+            feeRateBps: U256::ZERO,
+            side: OrderSide::Buy.to_u8(),
+            signatureType: 0,
+        }
+    }
+
+    #[test]
+    fn authorized_hash_matches_local_signature() {
+        let wallet = PrivateKeySigner::random();
+        let order = buy_order(wallet.address());
+        let domain = order_domain(POLYGON_EXCHANGE_CONTRACT);
+        let request = RemoteSignRequest::new(&order, &domain);
+
+        let hash = SigningPolicy::default().authorize(wallet.address(), &request).unwrap();
+        let signature = wallet.sign_hash_sync(&hash).unwrap();
+
+        assert_eq!(signature, wallet.sign_typed_data_sync(&order, &domain).unwrap());
+        assert_eq!(signature.recover_address_from_prehash(&hash).unwrap(), wallet.address());
+    }
+
+    #[test]
+    fn rejects_order_over_max_notional() {
+        let wallet = PrivateKeySigner::random();
+        let order = buy_order(wallet.address());
+        let request = RemoteSignRequest::new(&order, &order_domain(POLYGON_EXCHANGE_CONTRACT));
+
+        let policy = SigningPolicy { max_notional: Some(Decimal::from(100)), ..Default::default() };
+        assert!(policy.authorize(wallet.address(), &request).is_ok());
+
+        let policy = SigningPolicy { max_notional: Some(Decimal::from(99)), ..Default::default() };
+        assert!(policy.authorize(wallet.address(), &request).is_err());
+    }
+
+    #[test]
+    fn rejects_disallowed_exchange() {
+        let wallet = PrivateKeySigner::random();
+        let order = buy_order(wallet.address());
+        let request = RemoteSignRequest::new(&order, &order_domain(Address::repeat_byte(0x11)));
+
+        assert!(SigningPolicy::default().authorize(wallet.address(), &request).is_err());
+    }
+
+    #[test]
+    fn rejects_other_chain_or_version() {
+        let wallet = PrivateKeySigner::random();
+        let order = buy_order(wallet.address());
+
+        let mut domain = order_domain(POLYGON_EXCHANGE_CONTRACT);
+        domain.chain_id = Some(U256::from(1));
+        let request = RemoteSignRequest::new(&order, &domain);
+        assert!(SigningPolicy::default().authorize(wallet.address(), &request).is_err());
+
+        let mut domain = order_domain(POLYGON_EXCHANGE_CONTRACT);
+        domain.version = Some("2".into());
+        let request = RemoteSignRequest::new(&order, &domain);
+        assert!(SigningPolicy::default().authorize(wallet.address(), &request).is_err());
+    }
+
+    #[test]
+    fn rejects_order_for_another_signer() {
+        let wallet = PrivateKeySigner::random();
+        let order = buy_order(Address::repeat_byte(0x22));
+        let request = RemoteSignRequest::new(&order, &order_domain(POLYGON_EXCHANGE_CONTRACT));
+
+        assert!(SigningPolicy::default().authorize(wallet.address(), &request).is_err());
+    }
+}
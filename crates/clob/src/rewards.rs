@@ -0,0 +1,219 @@
+use crate::{ConditionId, TokenId, deserialize_decimal};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// `next_cursor` value marking the last page of a paginated CLOB response.
+pub const END_CURSOR: &str = "LTE=";
+
+/// One page of a cursor-paginated CLOB response.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Page<T> {
+    #[serde(default)]
+    pub limit: u64,
+    #[serde(default)]
+    pub count: u64,
+    #[serde(default)]
+    pub next_cursor: String,
+    pub data: Vec<T>,
+}
+
+impl<T> Page<T> {
+    pub fn is_last(&self) -> bool {
+        self.next_cursor.is_empty() || self.next_cursor == END_CURSOR
+    }
+
+    /// Splits the page into its items and the cursor of the next page, `None` on the last page.
+    pub fn into_parts(self) -> (Vec<T>, Option<String>) {
+        let next_cursor = (!self.is_last()).then_some(self.next_cursor);
+        (self.data, next_cursor)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct OrderScoring {
+    pub scoring: bool,
+}
+
+/// Reward emission of a market in one asset over a date range.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RewardsConfig {
+    pub asset_address: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub rate_per_day: Decimal,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub total_rewards: Decimal,
+}
+
+/// Market currently paying liquidity rewards, from `/rewards/markets/current`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CurrentReward {
+    pub condition_id: ConditionId,
+    /// Maximum distance from the midpoint (in cents) for an order to score
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub rewards_max_spread: Decimal,
+    /// Minimum order size (in shares) for an order to score
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub rewards_min_size: Decimal,
+    #[serde(default)]
+    pub rewards_config: Vec<RewardsConfig>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RewardsToken {
+    pub token_id: TokenId,
+    pub outcome: String,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub price: Decimal,
+}
+
+/// Rewards parameters of a single market, from `/rewards/markets/{condition_id}`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RewardsMarket {
+    pub condition_id: ConditionId,
+    #[serde(default)]
+    pub question: String,
+    #[serde(default)]
+    pub market_slug: String,
+    #[serde(default)]
+    pub event_slug: String,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub rewards_max_spread: Decimal,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub rewards_min_size: Decimal,
+    #[serde(default, deserialize_with = "deserialize_decimal")]
+    pub market_competitiveness: Decimal,
+    #[serde(default)]
+    pub tokens: Vec<RewardsToken>,
+    #[serde(default)]
+    pub rewards_config: Vec<RewardsConfig>,
+}
+
+/// Rewards earned by a maker in one market on one day, from `/rewards/user`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UserEarning {
+    pub date: NaiveDate,
+    pub condition_id: ConditionId,
+    pub asset_address: String,
+    pub maker_address: String,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub earnings: Decimal,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub asset_rate: Decimal,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CursorQuery<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<&'a str>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UserEarningsQuery<'a> {
+    pub date: NaiveDate,
+    pub signature_type: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<&'a str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONDITION: &str = "0xe3b423dfad8c22ff75c9899c4e8176f628cf4ad4caa00481764d320e7415f7a9";
+
+    #[test]
+    fn last_page_has_no_next_cursor() {
+        let json = format!(
+            r#"{{
+                "limit": 500,
+                "count": 1,
+                "next_cursor": "LTE=",
+                "data": [{{
+                    "condition_id": "{CONDITION}",
+                    "rewards_max_spread": 3.5,
+                    "rewards_min_size": "50",
+                    "rewards_config": [{{
+                        "asset_address": "0x2791bca1f2de4661ed88a30c99a7a9449aa84174",
+                        "start_date": "2024-11-01",
+                        "end_date": "2500-12-31",
+                        "rate_per_day": 25,
+                        "total_rewards": "0"
+                    }}]
+                }}]
+            }}"#
+        );
+        let page: Page<CurrentReward> = serde_json::from_str(&json).unwrap();
+        assert!(page.is_last());
+
+        let (rewards, next_cursor) = page.into_parts();
+        assert_eq!(next_cursor, None);
+        assert_eq!(rewards[0].condition_id, CONDITION.parse().unwrap());
+        assert_eq!(rewards[0].rewards_max_spread, Decimal::new(35, 1));
+        assert_eq!(rewards[0].rewards_min_size, Decimal::from(50));
+        assert_eq!(rewards[0].rewards_config[0].rate_per_day, Decimal::from(25));
+        assert_eq!(
+            rewards[0].rewards_config[0].start_date,
+            NaiveDate::from_ymd_opt(2024, 11, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn middle_page_returns_next_cursor() {
+        let page: Page<OrderScoring> =
+            serde_json::from_str(r#"{"next_cursor": "MTAw", "data": [{"scoring": true}]}"#)
+                .unwrap();
+        assert!(!page.is_last());
+        assert_eq!(page.into_parts(), (vec![OrderScoring { scoring: true }], Some("MTAw".into())));
+
+        let page: Page<OrderScoring> = serde_json::from_str(r#"{"data": []}"#).unwrap();
+        assert!(page.is_last());
+    }
+
+    #[test]
+    fn deserializes_rewards_market() {
+        let json = format!(
+            r#"{{
+                "condition_id": "{CONDITION}",
+                "question": "Will it rain?",
+                "market_slug": "will-it-rain",
+                "event_slug": "rain",
+                "rewards_max_spread": "3",
+                "rewards_min_size": 20,
+                "market_competitiveness": 12.75,
+                "tokens": [
+                    {{"token_id": "123", "outcome": "Yes", "price": 0.62}},
+                    {{"token_id": "456", "outcome": "No", "price": "0.38"}}
+                ],
+                "rewards_config": []
+            }}"#
+        );
+        let market: RewardsMarket = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(market.market_slug, "will-it-rain");
+        assert_eq!(market.market_competitiveness, Decimal::new(1275, 2));
+        assert_eq!(market.tokens[0].token_id, "123".parse().unwrap());
+        assert_eq!(market.tokens[1].price, Decimal::new(38, 2));
+    }
+
+    #[test]
+    fn deserializes_user_earning() {
+        let json = format!(
+            r#"{{
+                "date": "2025-01-15",
+                "condition_id": "{CONDITION}",
+                "asset_address": "0x2791bca1f2de4661ed88a30c99a7a9449aa84174",
+                "maker_address": "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266",
+                "earnings": 0.734512,
+                "asset_rate": "1"
+            }}"#
+        );
+        let earning: UserEarning = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(earning.date, NaiveDate::from_ymd_opt(2025, 1, 15).unwrap());
+        assert_eq!(earning.earnings, Decimal::new(734512, 6));
+        assert_eq!(earning.asset_rate, Decimal::ONE);
+    }
+}
@@ -0,0 +1,304 @@
+use crate::OrderSide;
+use rust_decimal::Decimal;
+
+/// Divisor applied to a single-sided score when the midpoint is in `[0.10, 0.90]`.
+pub const SINGLE_SIDED_SCALING_FACTOR: Decimal = Decimal::from_parts(3, 0, 0, false, 0);
+
+/// Below / above these midpoints only two-sided liquidity scores.
+pub const TWO_SIDED_MIDPOINT_LOW: Decimal = Decimal::from_parts(10, 0, 0, false, 2);
+pub const TWO_SIDED_MIDPOINT_HIGH: Decimal = Decimal::from_parts(90, 0, 0, false, 2);
+
+const CENTS: Decimal = Decimal::ONE_HUNDRED;
+
+/// Reward parameters of a market, as returned by the rewards endpoints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RewardParams {
+    /// Maximum distance from the adjusted midpoint, in cents (`rewards_max_spread`)
+    pub max_spread: Decimal,
+    /// Minimum order size in shares (`rewards_min_size`)
+    pub min_size: Decimal,
+    /// Divisor for single-sided liquidity (`c`)
+    pub scaling_factor: Decimal,
+}
+
+impl RewardParams {
+    pub const fn new(max_spread: Decimal, min_size: Decimal) -> Self {
+        Self { max_spread, min_size, scaling_factor: SINGLE_SIDED_SCALING_FACTOR }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BookLevel {
+    pub price: Decimal,
+    pub size: Decimal,
+}
+
+/// Order book of a market's first outcome token; complement-token liquidity is expected to be
+/// mirrored into it (a NO bid at `p` is a YES ask at `1 - p`), as the CLOB book does.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BookSnapshot {
+    pub bids: Vec<BookLevel>,
+    pub asks: Vec<BookLevel>,
+}
+
+/// Which of the market's two tokens an order rests on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutcomeToken {
+    /// The first outcome, the token the book is quoted in
+    Primary,
+    /// The complementary outcome
+    Complement,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RestingOrder {
+    pub token: OutcomeToken,
+    pub side: OrderSide,
+    pub price: Decimal,
+    pub size: Decimal,
+}
+
+impl RestingOrder {
+    /// Side and price of the order expressed on the primary token.
+    pub fn to_primary(&self) -> (OrderSide, Decimal) {
+        match (self.token, self.side) {
+            (OutcomeToken::Primary, side) => (side, self.price),
+            (OutcomeToken::Complement, OrderSide::Buy) => {
+                (OrderSide::Sell, Decimal::ONE - self.price)
+            }
+            (OutcomeToken::Complement, OrderSide::Sell) => {
+                (OrderSide::Buy, Decimal::ONE - self.price)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RewardScore {
+    /// Size-cutoff-adjusted midpoint the spreads were measured from
+    pub midpoint: Decimal,
+    /// Score of liquidity buying the primary token (its bids and complement asks)
+    pub q_one: Decimal,
+    /// Score of liquidity selling the primary token (its asks and complement bids)
+    pub q_two: Decimal,
+    /// Final score after the two-sided rule
+    pub q_min: Decimal,
+}
+
+/// Midpoint between the best bid and ask that are at least `min_size`, ignoring dust orders.
+pub fn adjusted_midpoint(book: &BookSnapshot, min_size: Decimal) -> Option<Decimal> {
+    let best_bid =
+        book.bids.iter().filter(|level| level.size >= min_size).map(|level| level.price).max()?;
+    let best_ask =
+        book.asks.iter().filter(|level| level.size >= min_size).map(|level| level.price).min()?;
+    Some((best_bid + best_ask) / Decimal::TWO)
+}
+
+/// Spread score `S(v, s) = ((v - s) / v)^2` of an order `spread` cents from the midpoint.
+pub fn spread_score(max_spread: Decimal, spread: Decimal) -> Decimal {
+    if max_spread <= Decimal::ZERO || spread >= max_spread {
+        return Decimal::ZERO;
+    }
+    let ratio = (max_spread - spread) / max_spread;
+    ratio * ratio
+}
+
+/// Score of a set of primary-token `(side, price, size)` quotes around `midpoint`.
+fn score_quotes(
+    params: &RewardParams,
+    midpoint: Decimal,
+    quotes: impl IntoIterator<Item = (OrderSide, Decimal, Decimal)>,
+) -> RewardScore {
+    let mut q_one = Decimal::ZERO;
+    let mut q_two = Decimal::ZERO;
+    for (side, price, size) in quotes {
+        if size < params.min_size {
+            continue;
+        }
+        let spread = (price - midpoint).abs() * CENTS;
+        let score = spread_score(params.max_spread, spread) * size;
+        match side {
+            OrderSide::Buy => q_one += score,
+            OrderSide::Sell => q_two += score,
+        }
+    }
+
+    let two_sided = q_one.min(q_two);
+    let q_min = if (TWO_SIDED_MIDPOINT_LOW..=TWO_SIDED_MIDPOINT_HIGH).contains(&midpoint) {
+        two_sided.max(q_one.max(q_two) / params.scaling_factor)
+    } else {
+        two_sided
+    };
+
+    RewardScore { midpoint, q_one, q_two, q_min }
+}
+
+/// Polymarket's quadratic liquidity reward score of `orders` against `book`.
+///
+/// Returns `None` when the book has no two-sided midpoint.
+pub fn estimate_reward_score(
+    params: &RewardParams,
+    book: &BookSnapshot,
+    orders: &[RestingOrder],
+) -> Option<RewardScore> {
+    let midpoint = adjusted_midpoint(book, params.min_size)?;
+    let quotes = orders.iter().map(|order| {
+        let (side, price) = order.to_primary();
+        (side, price, order.size)
+    });
+    Some(score_quotes(params, midpoint, quotes))
+}
+
+/// Score of the whole `book` as if it were a single maker, a proxy for competing liquidity.
+pub fn estimate_book_score(params: &RewardParams, book: &BookSnapshot) -> Option<RewardScore> {
+    let midpoint = adjusted_midpoint(book, params.min_size)?;
+    let bids = book.bids.iter().map(|level| (OrderSide::Buy, level.price, level.size));
+    let asks = book.asks.iter().map(|level| (OrderSide::Sell, level.price, level.size));
+    Some(score_quotes(params, midpoint, bids.chain(asks)))
+}
+
+/// Estimated share of the market's reward pool earned by `orders`, in `[0, 1]`.
+///
+/// `book` should hold the other makers' liquidity only; it is scored as a single maker, which
+/// overstates competitors quoting one-sided, so the estimate errs on the low side.
+pub fn estimate_reward_share(
+    params: &RewardParams,
+    book: &BookSnapshot,
+    orders: &[RestingOrder],
+) -> Option<Decimal> {
+    let ours = estimate_reward_score(params, book, orders)?.q_min;
+    let others = estimate_book_score(params, book)?.q_min;
+    let total = ours + others;
+    Some(if total.is_zero() { Decimal::ZERO } else { ours / total })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    fn level(price: &str, size: u32) -> BookLevel {
+        BookLevel { price: dec(price), size: Decimal::from(size) }
+    }
+
+    fn order(token: OutcomeToken, side: OrderSide, price: &str, size: u32) -> RestingOrder {
+        RestingOrder { token, side, price: dec(price), size: Decimal::from(size) }
+    }
+
+    /// 3 cent max spread, 10 share minimum, `c = 3`.
+    fn params() -> RewardParams {
+        RewardParams::new(Decimal::from(3), Decimal::from(10))
+    }
+
+    /// Best sized bid 0.49 and ask 0.52 (midpoint 0.505), plus a dust bid at 0.50.
+    fn book() -> BookSnapshot {
+        BookSnapshot {
+            bids: vec![level("0.49", 100), level("0.50", 5)],
+            asks: vec![level("0.52", 100)],
+        }
+    }
+
+    #[test]
+    fn adjusted_midpoint_ignores_dust() {
+        assert_eq!(adjusted_midpoint(&book(), Decimal::from(10)), Some(dec("0.505")));
+        assert_eq!(adjusted_midpoint(&book(), Decimal::ZERO), Some(dec("0.51")));
+    }
+
+    #[test]
+    fn spread_score_is_quadratic_and_zero_at_max_spread() {
+        let max = Decimal::from(3);
+        assert_eq!(spread_score(max, Decimal::ZERO), Decimal::ONE);
+        assert_eq!(spread_score(max, dec("1.5")), dec("0.25"));
+        assert_eq!(spread_score(max, max), Decimal::ZERO);
+        assert_eq!(spread_score(max, Decimal::from(4)), Decimal::ZERO);
+    }
+
+    #[test]
+    fn complement_orders_mirror_onto_primary() {
+        let bid = order(OutcomeToken::Complement, OrderSide::Buy, "0.48", 1);
+        assert_eq!(bid.to_primary(), (OrderSide::Sell, dec("0.52")));
+        let ask = order(OutcomeToken::Complement, OrderSide::Sell, "0.30", 1);
+        assert_eq!(ask.to_primary(), (OrderSide::Buy, dec("0.70")));
+    }
+
+    #[test]
+    fn two_sided_score_inside_band() {
+        // Bid 1.5c below the midpoint: 0.25 * 100; complement bid at 0.48 is an ask 1.5c above
+        // it: 0.25 * 50
+        let orders = [
+            order(OutcomeToken::Primary, OrderSide::Buy, "0.49", 100),
+            order(OutcomeToken::Complement, OrderSide::Buy, "0.48", 50),
+        ];
+        let score = estimate_reward_score(&params(), &book(), &orders).unwrap();
+
+        assert_eq!(score.midpoint, dec("0.505"));
+        assert_eq!(score.q_one, Decimal::from(25));
+        assert_eq!(score.q_two, dec("12.5"));
+        // max(min(25, 12.5), 25 / 3)
+        assert_eq!(score.q_min, dec("12.5"));
+    }
+
+    #[test]
+    fn one_sided_score_inside_band_is_divided_by_c() {
+        let orders = [order(OutcomeToken::Primary, OrderSide::Buy, "0.49", 100)];
+        let score = estimate_reward_score(&params(), &book(), &orders).unwrap();
+
+        assert_eq!(score.q_two, Decimal::ZERO);
+        assert_eq!(score.q_min, Decimal::from(25) / Decimal::from(3));
+    }
+
+    #[test]
+    fn one_sided_score_outside_band_is_zero() {
+        // Midpoint 0.055
+        let book = BookSnapshot { bids: vec![level("0.04", 100)], asks: vec![level("0.07", 100)] };
+        let bid = order(OutcomeToken::Primary, OrderSide::Buy, "0.04", 90);
+        let ask = order(OutcomeToken::Primary, OrderSide::Sell, "0.07", 90);
+
+        let one_sided = estimate_reward_score(&params(), &book, &[bid]).unwrap();
+        // 1.5c away: ((3 - 1.5) / 3)^2 * 90 = 22.5
+        assert_eq!(one_sided.q_one, dec("22.5"));
+        assert_eq!(one_sided.q_min, Decimal::ZERO);
+
+        let two_sided = estimate_reward_score(&params(), &book, &[bid, ask]).unwrap();
+        assert_eq!(two_sided.q_min, dec("22.5"));
+    }
+
+    #[test]
+    fn orders_at_max_spread_or_below_min_size_do_not_score() {
+        let orders = [
+            order(OutcomeToken::Primary, OrderSide::Buy, "0.475", 100),
+            order(OutcomeToken::Primary, OrderSide::Buy, "0.40", 100),
+            order(OutcomeToken::Primary, OrderSide::Sell, "0.52", 9),
+        ];
+        let score = estimate_reward_score(&params(), &book(), &orders).unwrap();
+
+        assert_eq!(score.q_one, Decimal::ZERO);
+        assert_eq!(score.q_two, Decimal::ZERO);
+        assert_eq!(score.q_min, Decimal::ZERO);
+    }
+
+    #[test]
+    fn book_without_sized_ask_has_no_score() {
+        let book = BookSnapshot { bids: vec![level("0.49", 100)], asks: vec![level("0.52", 5)] };
+        let orders = [order(OutcomeToken::Primary, OrderSide::Buy, "0.49", 100)];
+
+        assert_eq!(estimate_reward_score(&params(), &book, &orders), None);
+        assert_eq!(estimate_reward_share(&params(), &book, &orders), None);
+    }
+
+    #[test]
+    fn reward_share_against_book() {
+        // Ours scores 12.5 (see above); the book scores min(25, 25) = 25
+        let orders = [
+            order(OutcomeToken::Primary, OrderSide::Buy, "0.49", 100),
+            order(OutcomeToken::Complement, OrderSide::Buy, "0.48", 50),
+        ];
+        let share = estimate_reward_share(&params(), &book(), &orders).unwrap();
+
+        assert_eq!(share, dec("12.5") / dec("37.5"));
+    }
+}
@@ -0,0 +1,126 @@
+use crate::Credentials;
+use alloy_signer_local::PrivateKeySigner;
+use anyhow::{Error, Result};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use zeroize::Zeroizing;
+
+/// File name of the encrypted wallet inside a [`CredentialStore`] directory.
+pub const WALLET_KEYSTORE_FILE: &str = "wallet.json";
+
+/// File name of the encrypted API credentials inside a [`CredentialStore`] directory.
+pub const CREDENTIALS_KEYSTORE_FILE: &str = "credentials.json";
+
+/// Password-encrypted storage for the trading wallet and its derived API credentials.
+///
+/// Both files use the Ethereum keystore v3 format (scrypt + AES-128-CTR): the wallet file is a
+/// standard keystore readable by other tools, the credentials file holds the JSON-encoded
+/// [`Credentials`] as its ciphertext. Decrypted material is wiped from memory after parsing.
+#[derive(Debug, Clone)]
+pub struct CredentialStore {
+    dir: PathBuf,
+}
+
+impl CredentialStore {
+    pub fn new(dir: impl AsRef<Path>) -> Self {
+        Self { dir: dir.as_ref().to_path_buf() }
+    }
+
+    pub fn wallet_path(&self) -> PathBuf {
+        self.dir.join(WALLET_KEYSTORE_FILE)
+    }
+
+    pub fn credentials_path(&self) -> PathBuf {
+        self.dir.join(CREDENTIALS_KEYSTORE_FILE)
+    }
+
+    pub fn save_wallet(&self, wallet: &PrivateKeySigner, password: &str) -> Result<()> {
+        let key = Zeroizing::new(wallet.to_bytes().0);
+        self.encrypt(key.as_slice(), password, WALLET_KEYSTORE_FILE)
+    }
+
+    pub fn load_wallet(&self, password: &str) -> Result<PrivateKeySigner> {
+        PrivateKeySigner::decrypt_keystore(self.wallet_path(), password)
+            .map_err(|e| Error::msg(format!("Failed to decrypt wallet: {e}")))
+    }
+
+    pub fn save_credentials(&self, creds: &Credentials, password: &str) -> Result<()> {
+        let plaintext = Zeroizing::new(serde_json::to_vec(creds)?);
+        self.encrypt(&plaintext, password, CREDENTIALS_KEYSTORE_FILE)
+    }
+
+    pub fn load_credentials(&self, password: &str) -> Result<Credentials> {
+        let plaintext = eth_keystore::decrypt_key(self.credentials_path(), password)
+            .map(Zeroizing::new)
+            .map_err(|e| Error::msg(format!("Failed to decrypt credentials: {e}")))?;
+        Ok(serde_json::from_slice(&plaintext)?)
+    }
+
+    fn encrypt(&self, plaintext: &[u8], password: &str, name: &str) -> Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        eth_keystore::encrypt_key(
+            &self.dir,
+            &mut rand08::thread_rng(),
+            plaintext,
+            password,
+            Some(name),
+        )
+        .map_err(|e| Error::msg(format!("Failed to encrypt {name}: {e}")))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+    use std::env;
+
+    /// Store in a fresh directory under the system temp dir.
+    fn temp_store() -> CredentialStore {
+        let name = format!("clob-store-{:016x}", rand::rng().random::<u64>());
+        CredentialStore::new(env::temp_dir().join(name))
+    }
+
+    fn creds() -> Credentials {
+        Credentials::new("api-key".into(), "c2VjcmV0LXNlY3JldA==".into(), "pass-phrase".into())
+    }
+
+    #[test]
+    fn round_trips_wallet_and_credentials() {
+        let store = temp_store();
+        let wallet = PrivateKeySigner::random();
+        store.save_wallet(&wallet, "hunter2").unwrap();
+        store.save_credentials(&creds(), "hunter2").unwrap();
+
+        assert_eq!(store.load_wallet("hunter2").unwrap().to_bytes(), wallet.to_bytes());
+        let loaded = store.load_credentials("hunter2").unwrap();
+        assert_eq!(loaded.api_key, "api-key");
+        assert_eq!(loaded.secret, "c2VjcmV0LXNlY3JldA==");
+        assert_eq!(loaded.passphrase, "pass-phrase");
+
+        fs::remove_dir_all(&store.dir).unwrap();
+    }
+
+    #[test]
+    fn wrong_password_fails() {
+        let store = temp_store();
+        store.save_wallet(&PrivateKeySigner::random(), "hunter2").unwrap();
+        store.save_credentials(&creds(), "hunter2").unwrap();
+
+        assert!(store.load_wallet("hunter3").is_err());
+        assert!(store.load_credentials("hunter3").is_err());
+
+        fs::remove_dir_all(&store.dir).unwrap();
+    }
+
+    #[test]
+    fn credentials_debug_is_redacted() {
+        let debug = format!("{:?}", creds());
+        assert!(debug.contains("api-key"));
+        assert!(!debug.contains("c2VjcmV0LXNlY3JldA=="));
+        assert!(!debug.contains("pass-phrase"));
+    }
+}
@@ -0,0 +1,58 @@
+//! Local HTTP stand-ins for tests.
+
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+/// Spawns a local HTTP server answering every request with the `(status, JSON body)` returned
+/// by `respond(path, body)`, and returns its base URL.
+pub(crate) async fn serve<F>(respond: F) -> String
+where
+    F: Fn(&str, &str) -> (u16, String) + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let mut request = Vec::new();
+            let mut buf = [0; 4096];
+            while !is_complete(&request) {
+                let n = stream.read(&mut buf).await.unwrap_or(0);
+                if n == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buf[..n]);
+            }
+            let request = String::from_utf8_lossy(&request);
+            let path = request.split_whitespace().nth(1).unwrap_or_default();
+            let body = request.split_once("\r\n\r\n").map_or("", |(_, body)| body);
+
+            let (status, body) = respond(path, body);
+            let response = format!(
+                "HTTP/1.1 {status} X\r\nContent-Type: application/json\r\n\
+                 Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            let _ = stream.write_all(response.as_bytes()).await;
+        }
+    });
+    base_url
+}
+
+fn body_start(request: &[u8]) -> Option<usize> {
+    request.windows(4).position(|w| w == b"\r\n\r\n").map(|end| end + 4)
+}
+
+/// Whether `request` holds the headers and the whole `Content-Length` body.
+fn is_complete(request: &[u8]) -> bool {
+    let Some(start) = body_start(request) else { return false };
+    let headers = String::from_utf8_lossy(&request[..start]).to_ascii_lowercase();
+    let length = headers
+        .lines()
+        .find_map(|line| line.strip_prefix("content-length:"))
+        .and_then(|value| value.trim().parse().ok())
+        .unwrap_or(0);
+    request.len() >= start + length
+}
@@ -0,0 +1,145 @@
+use crate::{ConditionId, OrderSide, Price, TickSize, TokenId};
+use anyhow::{Error, Result};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// `tick_size_change` event of the market channel, sent when a price nears 0 or 1 and the
+/// market switches to a finer tick (or back).
+#[derive(Debug, Clone, Deserialize)]
+pub struct TickSizeChange {
+    pub asset_id: TokenId,
+    pub market: ConditionId,
+    pub old_tick_size: TickSize,
+    pub new_tick_size: TickSize,
+    #[serde(default)]
+    pub timestamp: Option<String>,
+}
+
+/// What to do with a resting order after its token's tick size changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepriceAction {
+    /// The price is valid on the new tick
+    Keep(Price),
+    /// The price is off the new tick; replace the order at this (less aggressive) price
+    Replace(Price),
+    /// No valid price on the new tick keeps the order passive; cancel it
+    Cancel,
+}
+
+/// Current tick size of each token, kept up to date from `tick_size_change` events.
+#[derive(Debug, Clone, Default)]
+pub struct TickSizeTracker {
+    ticks: HashMap<TokenId, TickSize>,
+}
+
+impl TickSizeTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the tick size of `token_id`, e.g. from the market when subscribing.
+    pub fn insert(&mut self, token_id: TokenId, tick_size: TickSize) -> Option<TickSize> {
+        self.ticks.insert(token_id, tick_size)
+    }
+
+    pub fn get(&self, token_id: TokenId) -> Option<TickSize> {
+        self.ticks.get(&token_id).copied()
+    }
+
+    /// Applies a `tick_size_change` event, returning the tick size it replaced.
+    pub fn apply(&mut self, change: &TickSizeChange) -> Option<TickSize> {
+        self.insert(change.asset_id, change.new_tick_size)
+    }
+
+    /// `value` as a price on the current tick of `token_id`.
+    pub fn price(&self, token_id: TokenId, value: Decimal) -> Result<Price> {
+        let tick_size = self
+            .get(token_id)
+            .ok_or_else(|| Error::msg(format!("Unknown tick size for token {token_id}")))?;
+        Price::new(value, tick_size)
+    }
+
+    /// Checks a resting `side` order at `price` against the current tick of `token_id`.
+    /// Orders of unknown tokens are kept.
+    pub fn reprice(&self, token_id: TokenId, side: OrderSide, price: Price) -> RepriceAction {
+        let Some(tick_size) = self.get(token_id) else { return RepriceAction::Keep(price) };
+
+        if let Ok(price) = price.with_tick_size(tick_size) {
+            return RepriceAction::Keep(price);
+        }
+        match price.reprice(tick_size, side) {
+            Some(price) => RepriceAction::Replace(price),
+            None => RepriceAction::Cancel,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::U256;
+    use std::str::FromStr;
+
+    fn price(value: &str, tick_size: TickSize) -> Price {
+        Price::new(Decimal::from_str(value).unwrap(), tick_size).unwrap()
+    }
+
+    fn tracker(tick_size: TickSize) -> (TickSizeTracker, TokenId) {
+        let token_id = TokenId(U256::from(1));
+        let mut tracker = TickSizeTracker::new();
+        tracker.insert(token_id, tick_size);
+        (tracker, token_id)
+    }
+
+    #[test]
+    fn reprice_never_makes_orders_more_aggressive() {
+        let fine = price("0.125", TickSize::Thousandth);
+        assert_eq!(
+            fine.reprice(TickSize::Hundredth, OrderSide::Buy),
+            Some(price("0.12", TickSize::Hundredth))
+        );
+        assert_eq!(
+            fine.reprice(TickSize::Hundredth, OrderSide::Sell),
+            Some(price("0.13", TickSize::Hundredth))
+        );
+    }
+
+    #[test]
+    fn tracker_keeps_replaces_or_cancels() {
+        let (tracker, token_id) = tracker(TickSize::Hundredth);
+
+        let on_tick = price("0.420", TickSize::Thousandth);
+        assert_eq!(
+            tracker.reprice(token_id, OrderSide::Buy, on_tick),
+            RepriceAction::Keep(price("0.42", TickSize::Hundredth))
+        );
+
+        let off_tick = price("0.425", TickSize::Thousandth);
+        assert_eq!(
+            tracker.reprice(token_id, OrderSide::Sell, off_tick),
+            RepriceAction::Replace(price("0.43", TickSize::Hundredth))
+        );
+
+        // No bid on the coarser tick stays at or below 0.005
+        let bottom = price("0.005", TickSize::Thousandth);
+        assert_eq!(tracker.reprice(token_id, OrderSide::Buy, bottom), RepriceAction::Cancel);
+        assert_eq!(
+            tracker.reprice(token_id, OrderSide::Sell, bottom),
+            RepriceAction::Replace(price("0.01", TickSize::Hundredth))
+        );
+    }
+
+    #[test]
+    fn apply_updates_tick_size() {
+        let (mut tracker, token_id) = tracker(TickSize::Hundredth);
+        let change: TickSizeChange = serde_json::from_str(&format!(
+            r#"{{"asset_id":"{token_id}","market":"0x{}","old_tick_size":"0.01","new_tick_size":"0.001"}}"#,
+            "00".repeat(32)
+        ))
+        .unwrap();
+
+        assert_eq!(tracker.apply(&change), Some(TickSize::Hundredth));
+        assert_eq!(tracker.get(token_id), Some(TickSize::Thousandth));
+    }
+}
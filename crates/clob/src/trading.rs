@@ -1,13 +1,20 @@
 use crate::{
-    Credentials, OrderType, POLY_ADDR_HEADER, POLY_API_KEY_HEADER, POLY_PASS_HEADER,
-    POLY_SIG_HEADER, POLY_TS_HEADER, SignedOrderRequest, get_current_unix_time_secs, into_result,
+    AssetType, BalanceAllowance, BalanceAllowanceParams, ConditionId, Credentials, CurrentReward,
+    CursorQuery, DropNotificationsQuery, GTD_EXPIRATION_BUFFER_SECS, Heartbeat, HeartbeatRequest,
+    Notification, NotificationsQuery, OrderId, OrderScoring, OrderType, POLY_ADDR_HEADER,
+    POLY_API_KEY_HEADER, POLY_PASS_HEADER, POLY_SIG_HEADER, POLY_TS_HEADER, Page, RewardsMarket,
+    ServerClock, SignatureType, SignedOrderRequest, TokenId, UserEarning, UserEarningsQuery,
+    get_current_unix_time_secs, into_result,
 };
 use alloy_primitives::hex::encode_prefixed;
+use alloy_signer::Signer;
 use alloy_signer_local::PrivateKeySigner;
-use anyhow::Result;
+use anyhow::{Error, Result, bail};
 use base64::{Engine, engine::general_purpose::URL_SAFE};
+use chrono::NaiveDate;
 use hmac::{Hmac, Mac};
-use serde::Serialize;
+use reqwest::Method;
+use serde::{Serialize, de::DeserializeOwned};
 use sha2::Sha256;
 use std::collections::HashMap;
 
@@ -31,20 +38,56 @@ impl PostOrder {
     ) -> Self {
         Self { order, owner, order_type, defer_exec }
     }
+
+    /// Checks that the order's expiration matches its type: GTD orders need an expiration past
+    /// the server's security threshold at `now_secs` (server time, see [`ServerClock::now_secs`]),
+    /// every other type must have none.
+    pub fn validate(&self, now_secs: u64) -> Result<()> {
+        let expiration: u64 = self
+            .order
+            .expiration
+            .parse()
+            .map_err(|e| Error::msg(format!("Invalid expiration: {e}")))?;
+
+        match self.order_type {
+            OrderType::Gtd => {
+                let min_expiration = now_secs + GTD_EXPIRATION_BUFFER_SECS;
+                if expiration <= min_expiration {
+                    bail!("GTD expiration {expiration} must be after {min_expiration}");
+                }
+            }
+            order_type if expiration != 0 => {
+                bail!("{order_type:?} orders must not have an expiration, got {expiration}");
+            }
+            _ => {}
+        }
+        Ok(())
+    }
 }
 
+/// Client for the L2 (API-key authenticated) CLOB endpoints.
 #[derive(Clone)]
-pub struct TradingClient {
+pub struct TradingClient<S = PrivateKeySigner> {
     api_base: String,
     client: reqwest::Client,
-    wallet: PrivateKeySigner,
+    wallet: S,
     creds: Credentials,
+    clock: ServerClock,
 }
 
-impl TradingClient {
-    pub fn new(api_base: &str, wallet: PrivateKeySigner, creds: Credentials) -> Result<Self> {
+impl<S> TradingClient<S>
+where
+    S: Signer + Send + Sync,
+{
+    pub fn new(api_base: &str, wallet: S, creds: Credentials) -> Result<Self> {
         let client = reqwest::Client::builder().build()?;
-        Ok(Self { api_base: api_base.to_string(), client, wallet, creds })
+        Ok(Self {
+            api_base: api_base.to_string(),
+            client,
+            wallet,
+            creds,
+            clock: ServerClock::local(),
+        })
     }
 
     pub async fn post_order(
@@ -53,34 +96,232 @@ impl TradingClient {
         order_type: OrderType,
     ) -> Result<serde_json::Value> {
         let post_order = PostOrder::new(order, self.creds.api_key.clone(), order_type, false);
-        let headers =
-            create_l2_headers(&self.wallet, &self.creds, "POST", "/order", Some(&post_order))?;
-        let url = format!("{}{}", self.api_base, "/order");
-        let mut request = self.client.post(&url).json(&post_order);
-        for (key, value) in headers {
-            request = request.header(key, value);
+        post_order.validate(self.clock.now_secs())?;
+        self.send_l2(Method::POST, "/order", Some(&post_order)).await
+    }
+
+    /// Cancels every open order of this API key.
+    pub async fn cancel_all(&self) -> Result<serde_json::Value> {
+        self.send_l2::<(), _>(Method::DELETE, "/cancel-all", None).await
+    }
+
+    /// Sends one heartbeat. Pass `None` for the first beat and the returned ID afterwards; the
+    /// CLOB cancels all orders when beats stop arriving.
+    pub async fn post_heartbeat(&self, heartbeat_id: Option<&str>) -> Result<Heartbeat> {
+        let body = HeartbeatRequest { heartbeat_id };
+        self.send_l2(Method::POST, "/v1/heartbeats", Some(&body)).await
+    }
+
+    /// USDC (`AssetType::Collateral`) or outcome token (`AssetType::Conditional`, with
+    /// `token_id`) balance and exchange allowances of the funder behind `sig_type`.
+    pub async fn get_balance_allowance(
+        &self,
+        asset_type: AssetType,
+        token_id: Option<TokenId>,
+        sig_type: SignatureType,
+    ) -> Result<BalanceAllowance> {
+        let params = BalanceAllowanceParams::new(asset_type, token_id, sig_type);
+        self.get_l2("/balance-allowance", &params).await
+    }
+
+    /// Asks the CLOB to refresh its cached balance and allowances from chain, e.g. after a
+    /// deposit or approval.
+    pub async fn update_balance_allowance(
+        &self,
+        asset_type: AssetType,
+        token_id: Option<TokenId>,
+        sig_type: SignatureType,
+    ) -> Result<serde_json::Value> {
+        let params = BalanceAllowanceParams::new(asset_type, token_id, sig_type);
+        self.get_l2("/balance-allowance/update", &params).await
+    }
+
+    /// Whether a resting order currently earns liquidity rewards.
+    pub async fn is_order_scoring(&self, order_id: OrderId) -> Result<bool> {
+        let scoring: OrderScoring =
+            self.get_l2("/order-scoring", &[("order_id", order_id)]).await?;
+        Ok(scoring.scoring)
+    }
+
+    /// Scoring status of several orders at once, keyed by order ID.
+    pub async fn are_orders_scoring(
+        &self,
+        order_ids: &[OrderId],
+    ) -> Result<HashMap<OrderId, bool>> {
+        self.send_l2(Method::POST, "/orders-scoring", Some(order_ids)).await
+    }
+
+    /// All markets currently paying liquidity rewards.
+    pub async fn get_current_rewards(&self) -> Result<Vec<CurrentReward>> {
+        let mut rewards = Vec::new();
+        let mut next_cursor: Option<String> = None;
+        loop {
+            let query = CursorQuery { next_cursor: next_cursor.as_deref() };
+            let page: Page<CurrentReward> =
+                self.get_public("/rewards/markets/current", &query).await?;
+            let (data, cursor) = page.into_parts();
+            rewards.extend(data);
+            next_cursor = cursor;
+            if next_cursor.is_none() {
+                return Ok(rewards);
+            }
         }
+    }
 
-        let response = request.send().await?;
+    /// Rewards parameters (spread, min size, emissions) of the market `condition_id`.
+    pub async fn get_rewards_markets(
+        &self,
+        condition_id: ConditionId,
+    ) -> Result<Vec<RewardsMarket>> {
+        let path = format!("/rewards/markets/{condition_id}");
+        let mut markets = Vec::new();
+        let mut next_cursor: Option<String> = None;
+        loop {
+            let query = CursorQuery { next_cursor: next_cursor.as_deref() };
+            let page: Page<RewardsMarket> = self.get_public(&path, &query).await?;
+            let (data, cursor) = page.into_parts();
+            markets.extend(data);
+            next_cursor = cursor;
+            if next_cursor.is_none() {
+                return Ok(markets);
+            }
+        }
+    }
+
+    /// Per-market rewards earned by the funder behind `sig_type` on `date` (UTC).
+    pub async fn get_earnings_for_user_for_day(
+        &self,
+        date: NaiveDate,
+        sig_type: SignatureType,
+    ) -> Result<Vec<UserEarning>> {
+        let mut earnings = Vec::new();
+        let mut next_cursor: Option<String> = None;
+        loop {
+            let query = UserEarningsQuery {
+                date,
+                signature_type: sig_type.to_u8(),
+                next_cursor: next_cursor.as_deref(),
+            };
+            let page: Page<UserEarning> = self.get_l2("/rewards/user", &query).await?;
+            let (data, cursor) = page.into_parts();
+            earnings.extend(data);
+            next_cursor = cursor;
+            if next_cursor.is_none() {
+                return Ok(earnings);
+            }
+        }
+    }
+
+    /// Pending notifications (cancellations, fills, resolutions) of the funder behind `sig_type`.
+    pub async fn get_notifications(&self, sig_type: SignatureType) -> Result<Vec<Notification>> {
+        let query = NotificationsQuery { signature_type: sig_type.to_u8() };
+        self.get_l2("/notifications", &query).await
+    }
+
+    /// Marks notifications as read so they are no longer returned.
+    pub async fn drop_notifications(&self, ids: &[&str]) -> Result<serde_json::Value> {
+        let query = DropNotificationsQuery { ids: ids.join(",") };
+        self.query_l2(Method::DELETE, "/notifications", &query).await
+    }
+
+    /// Current server time in Unix seconds.
+    pub async fn get_server_time(&self) -> Result<u64> {
+        let url = format!("{}{}", self.api_base, "/time");
+
+        let response = self.client.get(&url).send().await?;
         into_result(response).await
     }
 
+    /// Clock used to validate GTD expirations, the local clock until [`Self::sync_clock`].
+    pub const fn clock(&self) -> ServerClock {
+        self.clock
+    }
+
+    /// Measures the offset between the local and server clocks and keeps it for validating GTD
+    /// expirations.
+    pub async fn sync_clock(&mut self) -> Result<ServerClock> {
+        let server_time = self.get_server_time().await?;
+        self.clock = ServerClock::from_server_time(server_time);
+        Ok(self.clock)
+    }
+
     pub async fn ok(&self) -> Result<serde_json::Value> {
         let url = format!("{}{}", self.api_base, "/ok");
 
         let response = self.client.get(&url).send().await?;
         into_result(response).await
     }
+
+    /// Sends an unauthenticated GET.
+    async fn get_public<Q, T>(&self, path: &str, query: &Q) -> Result<T>
+    where
+        Q: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        let url = format!("{}{}", self.api_base, path);
+
+        let response = self.client.get(&url).query(query).send().await?;
+        into_result(response).await
+    }
+
+    /// Sends an L2-authenticated request with an optional JSON body.
+    async fn send_l2<B, T>(&self, method: Method, path: &str, body: Option<&B>) -> Result<T>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        let headers = create_l2_headers(&self.wallet, &self.creds, method.as_str(), path, body)?;
+        let url = format!("{}{}", self.api_base, path);
+        let mut request = self.client.request(method, &url);
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+        for (key, value) in headers {
+            request = request.header(key, value);
+        }
+
+        let response = request.send().await?;
+        into_result(response).await
+    }
+
+    /// Sends an L2-authenticated GET.
+    async fn get_l2<Q, T>(&self, path: &str, query: &Q) -> Result<T>
+    where
+        Q: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        self.query_l2(Method::GET, path, query).await
+    }
+
+    /// Sends an L2-authenticated request with query parameters. The HMAC covers the path only,
+    /// not the query string.
+    async fn query_l2<Q, T>(&self, method: Method, path: &str, query: &Q) -> Result<T>
+    where
+        Q: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        let headers =
+            create_l2_headers::<_, ()>(&self.wallet, &self.creds, method.as_str(), path, None)?;
+        let url = format!("{}{}", self.api_base, path);
+        let mut request = self.client.request(method, &url).query(query);
+        for (key, value) in headers {
+            request = request.header(key, value);
+        }
+
+        let response = request.send().await?;
+        into_result(response).await
+    }
 }
 
-pub fn create_l2_headers<T>(
-    signer: &PrivateKeySigner,
+pub fn create_l2_headers<S, T>(
+    signer: &S,
     api_creds: &Credentials,
     method: &str,
     req_path: &str,
     body: Option<&T>,
 ) -> Result<HashMap<&'static str, String>>
 where
+    S: Signer,
     T: ?Sized + Serialize,
 {
     let address = encode_prefixed(signer.address().as_slice());
@@ -149,3 +390,57 @@ where
     let result = mac.finalize();
     Ok(URL_SAFE.encode(result.into_bytes()))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OrderSide;
+
+    const NOW: u64 = 1_700_000_000;
+
+    fn post_order(order_type: OrderType, expiration: u64) -> PostOrder {
+        let order = SignedOrderRequest {
+            salt: 1,
+            maker: String::new(),
+            signer: String::new(),
+            taker: String::new(),
+            token_id: TokenId::default(),
+            maker_amount: "0".into(),
+            taker_amount: "0".into(),
+            expiration: expiration.to_string(),
+            nonce: "0".into(),
+            fee_rate_bps: "0".into(),
+            side: OrderSide::Buy,
+            signature_type: 0,
+            signature: String::new(),
+        };
+        PostOrder::new(order, String::new(), order_type, false)
+    }
+
+    #[test]
+    fn gtd_requires_expiration() {
+        assert!(post_order(OrderType::Gtd, 0).validate(NOW).is_err());
+    }
+
+    #[test]
+    fn gtd_expiration_must_pass_security_threshold() {
+        let threshold = NOW + GTD_EXPIRATION_BUFFER_SECS;
+        assert!(post_order(OrderType::Gtd, threshold).validate(NOW).is_err());
+        assert!(post_order(OrderType::Gtd, threshold + 1).validate(NOW).is_ok());
+    }
+
+    #[test]
+    fn gtd_uses_given_server_time() {
+        let expiration = NOW + GTD_EXPIRATION_BUFFER_SECS + 30;
+        assert!(post_order(OrderType::Gtd, expiration).validate(NOW).is_ok());
+        // A server clock 60s ahead of the local one makes the same expiration too short
+        assert!(post_order(OrderType::Gtd, expiration).validate(NOW + 60).is_err());
+    }
+
+    #[test]
+    fn non_gtd_rejects_expiration() {
+        assert!(post_order(OrderType::Gtc, NOW + 3600).validate(NOW).is_err());
+        assert!(post_order(OrderType::Fok, NOW + 3600).validate(NOW).is_err());
+        assert!(post_order(OrderType::Gtc, 0).validate(NOW).is_ok());
+    }
+}
@@ -1,6 +1,16 @@
+use crate::TOKEN_SCALE;
+use alloy_primitives::{Address, TxHash, U256};
+use alloy_provider::Provider;
+use alloy_rpc_types_eth::TransactionRequest;
 use anyhow::{Error, Result};
 use reqwest::Response;
-use std::time::{SystemTime, UNIX_EPOCH};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer, de};
+use std::{
+    collections::HashMap,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 #[inline]
 pub fn get_current_unix_time_secs() -> u64 {
@@ -16,3 +26,110 @@ pub async fn into_result<T: serde::de::DeserializeOwned>(resp: Response) -> Resu
         Err(Error::msg(format!("status{:?}, {:?}", status, text)))
     }
 }
+
+/// Converts a raw on-chain amount (6 decimals) into whole tokens, saturating at `Decimal::MAX`.
+pub fn token_amount_to_decimal(raw: U256) -> Decimal {
+    i128::try_from(raw)
+        .ok()
+        .and_then(|raw| Decimal::try_from_i128_with_scale(raw, 0).ok())
+        .map_or(Decimal::MAX, |raw| raw / TOKEN_SCALE)
+}
+
+/// Converts whole tokens into a raw on-chain amount (6 decimals), erroring on negative amounts and
+/// on more than 6 decimals.
+pub fn decimal_to_token_amount(amount: Decimal) -> Result<U256> {
+    let raw = amount * TOKEN_SCALE;
+    if raw.is_sign_negative() || !raw.fract().is_zero() {
+        return Err(Error::msg(format!("Invalid token amount: {amount}")));
+    }
+    let raw = u128::try_from(raw.trunc())
+        .map_err(|e| Error::msg(format!("Invalid token amount {amount}: {e}")))?;
+    Ok(U256::from(raw))
+}
+
+/// Sends `transaction` through `provider` and waits for it to be mined, erroring if it reverted.
+pub(crate) async fn send_and_confirm<P: Provider>(
+    provider: &P,
+    transaction: TransactionRequest,
+) -> Result<TxHash> {
+    let receipt = provider.send_transaction(transaction).await?.get_receipt().await?;
+    if !receipt.status() {
+        return Err(Error::msg(format!("Transaction {} reverted", receipt.transaction_hash)));
+    }
+    Ok(receipt.transaction_hash)
+}
+
+fn parse_token_amount<E: de::Error>(raw: &str) -> Result<Decimal, E> {
+    let raw = U256::from_str_radix(raw, 10)
+        .map_err(|e| E::custom(format!("Invalid token amount '{raw}': {e}")))?;
+    Ok(token_amount_to_decimal(raw))
+}
+
+pub fn deserialize_token_amount<'de, D>(deserializer: D) -> Result<Decimal, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let raw = String::deserialize(deserializer)?;
+    parse_token_amount(&raw)
+}
+
+pub fn deserialize_token_amount_map<'de, D>(
+    deserializer: D,
+) -> Result<HashMap<Address, Decimal>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let raw = HashMap::<Address, String>::deserialize(deserializer)?;
+    raw.into_iter().map(|(spender, amount)| Ok((spender, parse_token_amount(&amount)?))).collect()
+}
+
+/// Deserializes a `Decimal` sent either as a JSON number or as a numeric string.
+pub fn deserialize_decimal<'de, D>(deserializer: D) -> Result<Decimal, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = serde_json::Value::deserialize(deserializer)?;
+    let raw = match &value {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Number(n) => n.to_string(),
+        other => return Err(de::Error::custom(format!("Expected a decimal, got {other}"))),
+    };
+    Decimal::from_str(&raw)
+        .or_else(|_| Decimal::from_scientific(&raw))
+        .map_err(|e| de::Error::custom(format!("Failed to parse decimal from '{raw}': {e}")))
+}
+
+/// Deserializes an identifier sent either as a JSON string or as a number.
+pub fn deserialize_string_or_number<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::String(s) => Ok(s),
+        serde_json::Value::Number(n) => Ok(n.to_string()),
+        other => Err(de::Error::custom(format!("Expected a string or number, got {other}"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scales_raw_amounts_to_whole_tokens() {
+        assert_eq!(token_amount_to_decimal(U256::from(1_500_000)), Decimal::new(15, 1));
+        assert_eq!(token_amount_to_decimal(U256::from(1)), Decimal::new(1, 6));
+        assert_eq!(token_amount_to_decimal(U256::MAX), Decimal::MAX);
+        // Fits in an i128 but not in a Decimal
+        assert_eq!(token_amount_to_decimal(U256::from(u128::MAX >> 1)), Decimal::MAX);
+    }
+
+    #[test]
+    fn converts_whole_tokens_to_raw_amounts() {
+        assert_eq!(decimal_to_token_amount(Decimal::new(15, 1)).unwrap(), U256::from(1_500_000));
+        assert_eq!(decimal_to_token_amount(Decimal::new(1, 6)).unwrap(), U256::from(1));
+        assert_eq!(decimal_to_token_amount(Decimal::ZERO).unwrap(), U256::ZERO);
+        assert!(decimal_to_token_amount(Decimal::new(1, 7)).is_err());
+        assert!(decimal_to_token_amount(Decimal::new(-1, 0)).is_err());
+    }
+}
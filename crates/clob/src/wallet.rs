@@ -0,0 +1,64 @@
+use crate::{
+    POLYGON_PROXY_FACTORY_CONTRACT, POLYGON_PROXY_INIT_CODE_HASH, POLYGON_SAFE_FACTORY_CONTRACT,
+    POLYGON_SAFE_INIT_CODE_HASH, SignatureType,
+};
+use alloy_primitives::{Address, keccak256};
+
+/// Derives the Polymarket proxy wallet owned by `signer` (CREATE2 from the proxy factory, salted
+/// with `keccak256(abi.encodePacked(signer))`). The wallet need not be deployed yet.
+pub fn derive_proxy_wallet(signer: Address) -> Address {
+    let salt = keccak256(signer);
+    POLYGON_PROXY_FACTORY_CONTRACT.create2(salt, POLYGON_PROXY_INIT_CODE_HASH)
+}
+
+/// Derives the Polymarket Gnosis Safe owned by `signer` (CREATE2 from the Safe factory, salted
+/// with `keccak256(abi.encode(signer))`). The Safe need not be deployed yet.
+pub fn derive_safe_wallet(signer: Address) -> Address {
+    let salt = keccak256(signer.into_word());
+    POLYGON_SAFE_FACTORY_CONTRACT.create2(salt, POLYGON_SAFE_INIT_CODE_HASH)
+}
+
+/// Returns the address holding the funds (the order `maker`) for `signer` under `sig_type`.
+pub fn derive_funder(signer: Address, sig_type: SignatureType) -> Address {
+    match sig_type {
+        SignatureType::Eoa => signer,
+        SignatureType::PolyProxy => derive_proxy_wallet(signer),
+        SignatureType::PolyGnosisSafe => derive_safe_wallet(signer),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+
+    // First anvil / hardhat development account; the expected wallets were computed separately
+    // from the factory CREATE2 formulas.
+    const SIGNER: Address = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+    #[test]
+    fn derives_proxy_wallet() {
+        assert_eq!(
+            derive_proxy_wallet(SIGNER),
+            address!("365f0ca36ae1f641e02fe3b7743673da42a13a70")
+        );
+    }
+
+    #[test]
+    fn derives_safe_wallet() {
+        assert_eq!(
+            derive_safe_wallet(SIGNER),
+            address!("d93b25cb943d14d0d34fbaf01fc93a0f8b5f6e47")
+        );
+    }
+
+    #[test]
+    fn funder_follows_signature_type() {
+        assert_eq!(derive_funder(SIGNER, SignatureType::Eoa), SIGNER);
+        assert_eq!(derive_funder(SIGNER, SignatureType::PolyProxy), derive_proxy_wallet(SIGNER));
+        assert_eq!(
+            derive_funder(SIGNER, SignatureType::PolyGnosisSafe),
+            derive_safe_wallet(SIGNER)
+        );
+    }
+}
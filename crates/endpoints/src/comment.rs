@@ -0,0 +1,161 @@
+use crate::{
+    types::Comment,
+    utils::{DEFAULT_PAGE_LIMIT, into_result, paginate, push_opt},
+};
+use anyhow::{Error, Result};
+use futures::Stream;
+
+/// Entity a comment thread hangs off.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommentParent {
+    Event(String),
+    Market(String),
+    Series(String),
+}
+
+impl CommentParent {
+    fn to_query(&self) -> [(&'static str, &str); 2] {
+        let (kind, id) = match self {
+            Self::Event(id) => ("Event", id),
+            Self::Market(id) => ("market", id),
+            Self::Series(id) => ("Series", id),
+        };
+        [("parent_entity_type", kind), ("parent_entity_id", id)]
+    }
+}
+
+/// Paging and ordering of comment listings. Unset fields are not sent.
+#[derive(Debug, Clone, Default)]
+pub struct CommentFilter {
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+    /// Field to sort by, e.g. `createdAt`, `reactionCount`
+    pub order: Option<String>,
+    pub ascending: Option<bool>,
+    /// Include the commenters' positions in the market
+    pub get_positions: Option<bool>,
+    /// Only comments by holders of the market
+    pub holders_only: Option<bool>,
+}
+
+impl CommentFilter {
+    pub fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        push_opt(&mut query, "limit", self.limit);
+        push_opt(&mut query, "offset", self.offset);
+        push_opt(&mut query, "order", self.order.as_ref());
+        push_opt(&mut query, "ascending", self.ascending);
+        push_opt(&mut query, "get_positions", self.get_positions);
+        push_opt(&mut query, "holders_only", self.holders_only);
+        query
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CommentEndpoint {
+    api_base: String,
+    client: reqwest::Client,
+}
+
+impl CommentEndpoint {
+    pub fn new(api_base: &str) -> Result<Self> {
+        let client = reqwest::Client::builder().build()?;
+        Ok(Self { api_base: api_base.to_string(), client })
+    }
+}
+
+impl CommentEndpoint {
+    /// One page of comments on `parent`.
+    pub async fn list_comments(
+        &self,
+        parent: &CommentParent,
+        filter: &CommentFilter,
+    ) -> Result<Vec<Comment>, Error> {
+        let url = format!("{}/comments", self.api_base);
+        let request = self.client.get(&url).query(&parent.to_query()).query(&filter.to_query());
+
+        let response = request.send().await?;
+        into_result(response).await
+    }
+
+    /// Every comment on `parent`, fetched page by page.
+    pub fn list_comments_stream(
+        &self,
+        parent: CommentParent,
+        filter: CommentFilter,
+    ) -> impl Stream<Item = Result<Comment, Error>> + '_ {
+        let limit = filter.limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        let offset = filter.offset.unwrap_or_default();
+        paginate(limit, offset, move |limit, offset| {
+            let parent = parent.clone();
+            let filter =
+                CommentFilter { limit: Some(limit), offset: Some(offset), ..filter.clone() };
+            async move { self.list_comments(&parent, &filter).await }
+        })
+    }
+
+    /// The comment `id` and its replies.
+    pub async fn get_comment(&self, id: &str) -> Result<Vec<Comment>, Error> {
+        let url = format!("{}/comments/{}", self.api_base, id);
+        let request = self.client.get(&url);
+
+        let response = request.send().await?;
+        into_result(response).await
+    }
+
+    /// Comments written by `address` across all entities.
+    pub async fn comments_by_user(
+        &self,
+        address: &str,
+        filter: &CommentFilter,
+    ) -> Result<Vec<Comment>, Error> {
+        let url = format!("{}/comments/user_address/{}", self.api_base, address);
+        let request = self.client.get(&url).query(&filter.to_query());
+
+        let response = request.send().await?;
+        into_result(response).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parent_to_query() {
+        assert_eq!(
+            CommentParent::Event("1".into()).to_query(),
+            [("parent_entity_type", "Event"), ("parent_entity_id", "1")]
+        );
+        assert_eq!(
+            CommentParent::Market("2".into()).to_query()[0],
+            ("parent_entity_type", "market")
+        );
+        assert_eq!(
+            CommentParent::Series("3".into()).to_query()[0],
+            ("parent_entity_type", "Series")
+        );
+    }
+
+    #[test]
+    fn filter_to_query() {
+        assert!(CommentFilter::default().to_query().is_empty());
+
+        let filter = CommentFilter {
+            limit: Some(10),
+            order: Some("createdAt".into()),
+            get_positions: Some(true),
+            holders_only: Some(false),
+            ..Default::default()
+        };
+        assert_eq!(
+            filter.to_query(),
+            [
+                ("limit", "10".to_string()),
+                ("order", "createdAt".to_string()),
+                ("get_positions", "true".to_string()),
+                ("holders_only", "false".to_string()),
+            ]
+        );
+    }
+}
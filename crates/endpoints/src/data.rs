@@ -0,0 +1,486 @@
+use crate::{
+    types::{
+        Activity, ActivityType, ClosedPosition, LeaderboardEntry, LiveVolume, OpenInterest,
+        Position, TokenHolders, Trade, TradeSide, UserValue,
+    },
+    utils::{DEFAULT_PAGE_LIMIT, into_result, paginate, push_joined, push_opt},
+};
+use anyhow::{Error, Result};
+use chrono::{DateTime, Utc};
+use clob::ConditionId;
+use futures::Stream;
+use rust_decimal::Decimal;
+use serde::de::DeserializeOwned;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+impl SortDirection {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Asc => "ASC",
+            Self::Desc => "DESC",
+        }
+    }
+}
+
+/// Query parameters of `/positions`. Unset fields are not sent.
+#[derive(Debug, Clone, Default)]
+pub struct PositionFilter {
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+    /// Condition ids to restrict to
+    pub markets: Vec<ConditionId>,
+    pub event_ids: Vec<String>,
+    /// Minimum position size in shares
+    pub size_threshold: Option<Decimal>,
+    pub redeemable: Option<bool>,
+    pub mergeable: Option<bool>,
+    pub title: Option<String>,
+    /// e.g. `CURRENT`, `CASHPNL`, `TOKENS`
+    pub sort_by: Option<String>,
+    pub sort_direction: Option<SortDirection>,
+}
+
+impl PositionFilter {
+    pub fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        push_opt(&mut query, "limit", self.limit);
+        push_opt(&mut query, "offset", self.offset);
+        push_joined(&mut query, "market", &self.markets);
+        push_joined(&mut query, "eventId", &self.event_ids);
+        push_opt(&mut query, "sizeThreshold", self.size_threshold);
+        push_opt(&mut query, "redeemable", self.redeemable);
+        push_opt(&mut query, "mergeable", self.mergeable);
+        push_opt(&mut query, "title", self.title.as_ref());
+        push_opt(&mut query, "sortBy", self.sort_by.as_ref());
+        push_opt(&mut query, "sortDirection", self.sort_direction.map(|d| d.as_str()));
+        query
+    }
+}
+
+/// Query parameters of `/closed-positions`. Unset fields are not sent.
+#[derive(Debug, Clone, Default)]
+pub struct ClosedPositionFilter {
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+    pub markets: Vec<ConditionId>,
+    pub event_ids: Vec<String>,
+    pub title: Option<String>,
+    /// e.g. `REALIZEDPNL`, `TIMESTAMP`
+    pub sort_by: Option<String>,
+    pub sort_direction: Option<SortDirection>,
+}
+
+impl ClosedPositionFilter {
+    pub fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        push_opt(&mut query, "limit", self.limit);
+        push_opt(&mut query, "offset", self.offset);
+        push_joined(&mut query, "market", &self.markets);
+        push_joined(&mut query, "eventId", &self.event_ids);
+        push_opt(&mut query, "title", self.title.as_ref());
+        push_opt(&mut query, "sortBy", self.sort_by.as_ref());
+        push_opt(&mut query, "sortDirection", self.sort_direction.map(|d| d.as_str()));
+        query
+    }
+}
+
+/// Query parameters of `/activity`. Unset fields are not sent.
+#[derive(Debug, Clone, Default)]
+pub struct ActivityFilter {
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+    pub markets: Vec<ConditionId>,
+    pub types: Vec<ActivityType>,
+    pub start: Option<DateTime<Utc>>,
+    pub end: Option<DateTime<Utc>>,
+    pub side: Option<TradeSide>,
+    /// e.g. `TIMESTAMP`, `TOKENS`, `CASH`
+    pub sort_by: Option<String>,
+    pub sort_direction: Option<SortDirection>,
+}
+
+impl ActivityFilter {
+    pub fn to_query(&self) -> Vec<(&'static str, String)> {
+        let types: Vec<_> = self.types.iter().map(ActivityType::as_str).collect();
+        let mut query = Vec::new();
+        push_opt(&mut query, "limit", self.limit);
+        push_opt(&mut query, "offset", self.offset);
+        push_joined(&mut query, "market", &self.markets);
+        push_joined(&mut query, "type", &types);
+        push_opt(&mut query, "start", self.start.map(|start| start.timestamp()));
+        push_opt(&mut query, "end", self.end.map(|end| end.timestamp()));
+        push_opt(&mut query, "side", self.side.map(|side| side.as_str()));
+        push_opt(&mut query, "sortBy", self.sort_by.as_ref());
+        push_opt(&mut query, "sortDirection", self.sort_direction.map(|d| d.as_str()));
+        query
+    }
+}
+
+/// Query parameters of `/trades`; set `user`, `markets` or both. Unset fields are not sent.
+#[derive(Debug, Clone, Default)]
+pub struct TradeFilter {
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+    pub user: Option<String>,
+    pub markets: Vec<ConditionId>,
+    /// Only trades where the wallet was the taker (the API default)
+    pub taker_only: Option<bool>,
+    pub side: Option<TradeSide>,
+}
+
+impl TradeFilter {
+    pub fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        push_opt(&mut query, "limit", self.limit);
+        push_opt(&mut query, "offset", self.offset);
+        push_opt(&mut query, "user", self.user.as_ref());
+        push_joined(&mut query, "market", &self.markets);
+        push_opt(&mut query, "takerOnly", self.taker_only);
+        push_opt(&mut query, "side", self.side.map(|side| side.as_str()));
+        query
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LeaderboardWindow {
+    Day,
+    Week,
+    Month,
+    #[default]
+    All,
+}
+
+impl LeaderboardWindow {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Day => "DAY",
+            Self::Week => "WEEK",
+            Self::Month => "MONTH",
+            Self::All => "ALL",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LeaderboardOrder {
+    #[default]
+    Pnl,
+    Volume,
+}
+
+impl LeaderboardOrder {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pnl => "PNL",
+            Self::Volume => "VOL",
+        }
+    }
+}
+
+/// Query parameters of `/v1/leaderboard`. Unset fields are not sent.
+#[derive(Debug, Clone, Default)]
+pub struct LeaderboardFilter {
+    pub window: LeaderboardWindow,
+    pub order_by: LeaderboardOrder,
+    /// e.g. `OVERALL`, `POLITICS`, `SPORTS`
+    pub category: Option<String>,
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+    /// Restrict to a single wallet, to look up its rank
+    pub user: Option<String>,
+}
+
+impl LeaderboardFilter {
+    pub fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        push_opt(&mut query, "timePeriod", Some(self.window.as_str()));
+        push_opt(&mut query, "orderBy", Some(self.order_by.as_str()));
+        push_opt(&mut query, "category", self.category.as_ref());
+        push_opt(&mut query, "limit", self.limit);
+        push_opt(&mut query, "offset", self.offset);
+        push_opt(&mut query, "user", self.user.as_ref());
+        query
+    }
+}
+
+/// Client of the Data API (`DATA_API_URL`): positions, activity and trades of wallets.
+#[derive(Debug, Clone)]
+pub struct DataEndpoint {
+    api_base: String,
+    client: reqwest::Client,
+}
+
+impl DataEndpoint {
+    pub fn new(api_base: &str) -> Result<Self> {
+        let client = reqwest::Client::builder().build()?;
+        Ok(Self { api_base: api_base.trim_end_matches('/').to_string(), client })
+    }
+}
+
+impl DataEndpoint {
+    /// One page of the open positions of `user` (a proxy wallet address).
+    pub async fn positions(&self, user: &str, filter: &PositionFilter) -> Result<Vec<Position>> {
+        self.get("positions", Some(user), filter.to_query()).await
+    }
+
+    /// Every open position of `user` matching `filter`, fetched page by page.
+    pub fn positions_stream<'a>(
+        &'a self,
+        user: &'a str,
+        filter: PositionFilter,
+    ) -> impl Stream<Item = Result<Position>> + 'a {
+        let limit = filter.limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        let offset = filter.offset.unwrap_or_default();
+        paginate(limit, offset, move |limit, offset| {
+            let filter =
+                PositionFilter { limit: Some(limit), offset: Some(offset), ..filter.clone() };
+            async move { self.positions(user, &filter).await }
+        })
+    }
+
+    pub async fn closed_positions(
+        &self,
+        user: &str,
+        filter: &ClosedPositionFilter,
+    ) -> Result<Vec<ClosedPosition>> {
+        self.get("closed-positions", Some(user), filter.to_query()).await
+    }
+
+    pub fn closed_positions_stream<'a>(
+        &'a self,
+        user: &'a str,
+        filter: ClosedPositionFilter,
+    ) -> impl Stream<Item = Result<ClosedPosition>> + 'a {
+        let limit = filter.limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        let offset = filter.offset.unwrap_or_default();
+        paginate(limit, offset, move |limit, offset| {
+            let filter =
+                ClosedPositionFilter { limit: Some(limit), offset: Some(offset), ..filter.clone() };
+            async move { self.closed_positions(user, &filter).await }
+        })
+    }
+
+    pub async fn activity(&self, user: &str, filter: &ActivityFilter) -> Result<Vec<Activity>> {
+        self.get("activity", Some(user), filter.to_query()).await
+    }
+
+    pub fn activity_stream<'a>(
+        &'a self,
+        user: &'a str,
+        filter: ActivityFilter,
+    ) -> impl Stream<Item = Result<Activity>> + 'a {
+        let limit = filter.limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        let offset = filter.offset.unwrap_or_default();
+        paginate(limit, offset, move |limit, offset| {
+            let filter =
+                ActivityFilter { limit: Some(limit), offset: Some(offset), ..filter.clone() };
+            async move { self.activity(user, &filter).await }
+        })
+    }
+
+    /// One page of trades of `filter.user` and/or in `filter.markets`, newest first.
+    pub async fn trades(&self, filter: &TradeFilter) -> Result<Vec<Trade>> {
+        self.get("trades", None, filter.to_query()).await
+    }
+
+    pub fn trades_stream(&self, filter: TradeFilter) -> impl Stream<Item = Result<Trade>> + '_ {
+        let limit = filter.limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        let offset = filter.offset.unwrap_or_default();
+        paginate(limit, offset, move |limit, offset| {
+            let filter = TradeFilter { limit: Some(limit), offset: Some(offset), ..filter.clone() };
+            async move { self.trades(&filter).await }
+        })
+    }
+
+    /// Total value of the positions of `user`, optionally restricted to `markets`.
+    pub async fn value(&self, user: &str, markets: &[ConditionId]) -> Result<Decimal> {
+        let mut query = Vec::new();
+        push_joined(&mut query, "market", markets);
+        let values: Vec<UserValue> = self.get("value", Some(user), query).await?;
+
+        Ok(values.into_iter().map(|value| value.value).sum())
+    }
+
+    /// Top `limit` holders of each token of `markets` (condition ids).
+    pub async fn holders(
+        &self,
+        markets: &[ConditionId],
+        limit: Option<u64>,
+    ) -> Result<Vec<TokenHolders>> {
+        let mut query = Vec::new();
+        push_joined(&mut query, "market", markets);
+        push_opt(&mut query, "limit", limit);
+        self.get("holders", None, query).await
+    }
+
+    /// Open interest of each of `markets` (condition ids), or the platform total when empty.
+    pub async fn open_interest(&self, markets: &[ConditionId]) -> Result<Vec<OpenInterest>> {
+        let mut query = Vec::new();
+        push_joined(&mut query, "market", markets);
+        self.get("oi", None, query).await
+    }
+
+    /// Volume traded so far in the event `event_id`, broken down by market.
+    pub async fn live_volume(&self, event_id: &str) -> Result<LiveVolume> {
+        let query = vec![("id", event_id.to_string())];
+        let volumes: Vec<LiveVolume> = self.get("live-volume", None, query).await?;
+
+        Ok(volumes.into_iter().next().unwrap_or_default())
+    }
+
+    /// One page of the trader leaderboard, ranked by PnL or volume over `filter.window`.
+    pub async fn leaderboard(&self, filter: &LeaderboardFilter) -> Result<Vec<LeaderboardEntry>> {
+        self.get("v1/leaderboard", None, filter.to_query()).await
+    }
+
+    async fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        user: Option<&str>,
+        query: Vec<(&'static str, String)>,
+    ) -> Result<T, Error> {
+        let url = format!("{}/{}", self.api_base, path);
+        let mut request = self.client.get(&url).query(&query);
+        if let Some(user) = user {
+            request = request.query(&[("user", user)]);
+        }
+
+        let response = request.send().await?;
+        into_result(response).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn condition_id(n: u8) -> ConditionId {
+        format!("0x{n:064x}").parse().unwrap()
+    }
+
+    #[test]
+    fn empty_filters_send_nothing() {
+        assert!(PositionFilter::default().to_query().is_empty());
+        assert!(ClosedPositionFilter::default().to_query().is_empty());
+        assert!(ActivityFilter::default().to_query().is_empty());
+        assert!(TradeFilter::default().to_query().is_empty());
+    }
+
+    #[test]
+    fn position_filter_joins_lists() {
+        let filter = PositionFilter {
+            limit: Some(500),
+            markets: vec![condition_id(1), condition_id(2)],
+            event_ids: vec!["7".into()],
+            size_threshold: Some(Decimal::ONE),
+            redeemable: Some(true),
+            sort_by: Some("CASHPNL".into()),
+            sort_direction: Some(SortDirection::Asc),
+            ..Default::default()
+        };
+        assert_eq!(
+            filter.to_query(),
+            [
+                ("limit", "500".to_string()),
+                ("market", format!("{},{}", condition_id(1), condition_id(2))),
+                ("eventId", "7".to_string()),
+                ("sizeThreshold", "1".to_string()),
+                ("redeemable", "true".to_string()),
+                ("sortBy", "CASHPNL".to_string()),
+                ("sortDirection", "ASC".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn closed_position_filter_to_query() {
+        let filter = ClosedPositionFilter {
+            offset: Some(50),
+            markets: vec![condition_id(3)],
+            title: Some("Election".into()),
+            sort_direction: Some(SortDirection::Desc),
+            ..Default::default()
+        };
+        assert_eq!(
+            filter.to_query(),
+            [
+                ("offset", "50".to_string()),
+                ("market", condition_id(3).to_string()),
+                ("title", "Election".to_string()),
+                ("sortDirection", "DESC".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn activity_filter_sends_unix_seconds() {
+        let filter = ActivityFilter {
+            types: vec![ActivityType::Trade, ActivityType::Redeem],
+            start: DateTime::from_timestamp(1_700_000_000, 0),
+            end: DateTime::from_timestamp(1_700_003_600, 0),
+            side: Some(TradeSide::Sell),
+            ..Default::default()
+        };
+        assert_eq!(
+            filter.to_query(),
+            [
+                ("type", "TRADE,REDEEM".to_string()),
+                ("start", "1700000000".to_string()),
+                ("end", "1700003600".to_string()),
+                ("side", "SELL".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn trade_filter_to_query() {
+        let filter = TradeFilter {
+            user: Some("0xabc".into()),
+            markets: vec![condition_id(4)],
+            taker_only: Some(false),
+            side: Some(TradeSide::Buy),
+            ..Default::default()
+        };
+        assert_eq!(
+            filter.to_query(),
+            [
+                ("user", "0xabc".to_string()),
+                ("market", condition_id(4).to_string()),
+                ("takerOnly", "false".to_string()),
+                ("side", "BUY".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn leaderboard_filter_always_sends_window_and_order() {
+        assert_eq!(
+            LeaderboardFilter::default().to_query(),
+            [("timePeriod", "ALL".to_string()), ("orderBy", "PNL".to_string())]
+        );
+
+        let filter = LeaderboardFilter {
+            window: LeaderboardWindow::Week,
+            order_by: LeaderboardOrder::Volume,
+            category: Some("SPORTS".into()),
+            limit: Some(25),
+            user: Some("0xabc".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            filter.to_query(),
+            [
+                ("timePeriod", "WEEK".to_string()),
+                ("orderBy", "VOL".to_string()),
+                ("category", "SPORTS".to_string()),
+                ("limit", "25".to_string()),
+                ("user", "0xabc".to_string()),
+            ]
+        );
+    }
+}
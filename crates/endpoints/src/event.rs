@@ -0,0 +1,192 @@
+use crate::{
+    types::{Event, Series, Tag},
+    utils::{DEFAULT_PAGE_LIMIT, into_result, paginate, push_all, push_date, push_opt},
+};
+use anyhow::{Error, Result};
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use rust_decimal::Decimal;
+
+/// Query parameters of Gamma's `/events` listing. Unset fields are not sent.
+#[derive(Debug, Clone, Default)]
+pub struct EventFilter {
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+    /// Field to sort by, e.g. `volume`, `liquidity`, `startDate`
+    pub order: Option<String>,
+    pub ascending: Option<bool>,
+    pub ids: Vec<String>,
+    pub slugs: Vec<String>,
+    pub tag_id: Option<String>,
+    pub tag_slug: Option<String>,
+    pub exclude_tag_ids: Vec<String>,
+    pub related_tags: Option<bool>,
+    pub series_id: Option<String>,
+    pub active: Option<bool>,
+    pub closed: Option<bool>,
+    pub archived: Option<bool>,
+    pub featured: Option<bool>,
+    pub liquidity_min: Option<Decimal>,
+    pub liquidity_max: Option<Decimal>,
+    pub volume_min: Option<Decimal>,
+    pub volume_max: Option<Decimal>,
+    pub start_date_min: Option<DateTime<Utc>>,
+    pub start_date_max: Option<DateTime<Utc>>,
+    pub end_date_min: Option<DateTime<Utc>>,
+    pub end_date_max: Option<DateTime<Utc>>,
+}
+
+impl EventFilter {
+    /// Encodes the filter as query pairs; list fields repeat their key once per value.
+    pub fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        push_opt(&mut query, "limit", self.limit);
+        push_opt(&mut query, "offset", self.offset);
+        push_opt(&mut query, "order", self.order.as_ref());
+        push_opt(&mut query, "ascending", self.ascending);
+        push_all(&mut query, "id", &self.ids);
+        push_all(&mut query, "slug", &self.slugs);
+        push_opt(&mut query, "tag_id", self.tag_id.as_ref());
+        push_opt(&mut query, "tag_slug", self.tag_slug.as_ref());
+        push_all(&mut query, "exclude_tag_id", &self.exclude_tag_ids);
+        push_opt(&mut query, "related_tags", self.related_tags);
+        push_opt(&mut query, "series_id", self.series_id.as_ref());
+        push_opt(&mut query, "active", self.active);
+        push_opt(&mut query, "closed", self.closed);
+        push_opt(&mut query, "archived", self.archived);
+        push_opt(&mut query, "featured", self.featured);
+        push_opt(&mut query, "liquidity_min", self.liquidity_min);
+        push_opt(&mut query, "liquidity_max", self.liquidity_max);
+        push_opt(&mut query, "volume_min", self.volume_min);
+        push_opt(&mut query, "volume_max", self.volume_max);
+        push_date(&mut query, "start_date_min", self.start_date_min);
+        push_date(&mut query, "start_date_max", self.start_date_max);
+        push_date(&mut query, "end_date_min", self.end_date_min);
+        push_date(&mut query, "end_date_max", self.end_date_max);
+        query
+    }
+
+    /// Restricts the listing to events tagged with `tag`.
+    pub fn with_tag(self, tag: &Tag) -> Self {
+        Self { tag_id: Some(tag.id.clone()), ..self }
+    }
+
+    /// Excludes events tagged with `tag`.
+    pub fn without_tag(mut self, tag: &Tag) -> Self {
+        self.exclude_tag_ids.push(tag.id.clone());
+        self
+    }
+
+    /// Restricts the listing to events of `series`.
+    pub fn with_series(self, series: &Series) -> Self {
+        Self { series_id: Some(series.id.clone()), ..self }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EventEndpoint {
+    api_base: String,
+    client: reqwest::Client,
+}
+
+impl EventEndpoint {
+    pub fn new(api_base: &str) -> Result<Self> {
+        let client = reqwest::Client::builder().build()?;
+        Ok(Self { api_base: api_base.to_string(), client })
+    }
+}
+
+impl EventEndpoint {
+    pub async fn get_event_by_id(&self, id: &str) -> Result<Event, Error> {
+        let url = format!("{}/events/{}", self.api_base, id);
+        let request = self.client.get(&url);
+
+        let response = request.send().await?;
+        into_result(response).await
+    }
+
+    pub async fn get_event_by_slug(&self, slug: &str) -> Result<Event, Error> {
+        let url = format!("{}/events/slug/{}", self.api_base, slug);
+        let request = self.client.get(&url);
+
+        let response = request.send().await?;
+        into_result(response).await
+    }
+
+    /// One page of events matching `filter`.
+    pub async fn list_events(&self, filter: &EventFilter) -> Result<Vec<Event>, Error> {
+        let url = format!("{}/events", self.api_base);
+        let request = self.client.get(&url).query(&filter.to_query());
+
+        let response = request.send().await?;
+        into_result(response).await
+    }
+
+    /// Every event matching `filter`, fetched page by page (`filter.limit` per request)
+    /// starting at `filter.offset`.
+    pub fn list_events_stream(
+        &self,
+        filter: EventFilter,
+    ) -> impl Stream<Item = Result<Event, Error>> + '_ {
+        let limit = filter.limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        let offset = filter.offset.unwrap_or_default();
+        paginate(limit, offset, move |limit, offset| {
+            let filter = EventFilter { limit: Some(limit), offset: Some(offset), ..filter.clone() };
+            async move { self.list_events(&filter).await }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_filter_sends_nothing() {
+        assert!(EventFilter::default().to_query().is_empty());
+    }
+
+    #[test]
+    fn filter_repeats_list_keys() {
+        let filter = EventFilter {
+            offset: Some(100),
+            ids: vec!["1".into(), "2".into()],
+            tag_slug: Some("politics".into()),
+            exclude_tag_ids: vec!["7".into()],
+            related_tags: Some(true),
+            active: Some(true),
+            liquidity_min: Some(Decimal::from(1000)),
+            start_date_min: DateTime::from_timestamp(0, 0),
+            ..Default::default()
+        };
+        assert_eq!(
+            filter.to_query(),
+            [
+                ("offset", "100".to_string()),
+                ("id", "1".to_string()),
+                ("id", "2".to_string()),
+                ("tag_slug", "politics".to_string()),
+                ("exclude_tag_id", "7".to_string()),
+                ("related_tags", "true".to_string()),
+                ("active", "true".to_string()),
+                ("liquidity_min", "1000".to_string()),
+                ("start_date_min", "1970-01-01T00:00:00+00:00".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn tag_and_series_helpers() {
+        let tag = Tag { id: "21".into(), ..Default::default() };
+        let series = Series { id: "9".into(), ..Default::default() };
+        let filter = EventFilter::default().with_tag(&tag).without_tag(&tag).with_series(&series);
+        assert_eq!(
+            filter.to_query(),
+            [
+                ("tag_id", "21".to_string()),
+                ("exclude_tag_id", "21".to_string()),
+                ("series_id", "9".to_string()),
+            ]
+        );
+    }
+}
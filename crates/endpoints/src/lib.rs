@@ -1,7 +1,23 @@
+mod comment;
+mod data;
+mod event;
 mod market;
+mod registry;
+mod search;
+mod series;
+mod sports;
+mod tag;
 mod types;
 mod utils;
 
+pub use comment::*;
+pub use data::*;
+pub use event::*;
 pub use market::*;
+pub use registry::*;
+pub use search::*;
+pub use series::*;
+pub use sports::*;
+pub use tag::*;
 pub use types::*;
 pub use utils::*;
@@ -1,5 +1,73 @@
-use crate::types::Market;
+use crate::{
+    types::{Market, Tag},
+    utils::{DEFAULT_PAGE_LIMIT, into_result, paginate, push_all, push_date, push_opt},
+};
 use anyhow::{Error, Result};
+use chrono::{DateTime, Utc};
+use clob::{ConditionId, MarketSlug, TokenId};
+use futures::Stream;
+use rust_decimal::Decimal;
+
+/// Query parameters of Gamma's `/markets` listing. Unset fields are not sent.
+#[derive(Debug, Clone, Default)]
+pub struct MarketFilter {
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+    /// Field to sort by, e.g. `volume`, `liquidity`, `endDate`
+    pub order: Option<String>,
+    pub ascending: Option<bool>,
+    pub ids: Vec<String>,
+    pub slugs: Vec<MarketSlug>,
+    pub clob_token_ids: Vec<TokenId>,
+    pub condition_ids: Vec<ConditionId>,
+    pub tag_id: Option<String>,
+    pub related_tags: Option<bool>,
+    pub active: Option<bool>,
+    pub closed: Option<bool>,
+    pub archived: Option<bool>,
+    pub liquidity_num_min: Option<Decimal>,
+    pub liquidity_num_max: Option<Decimal>,
+    pub volume_num_min: Option<Decimal>,
+    pub volume_num_max: Option<Decimal>,
+    pub start_date_min: Option<DateTime<Utc>>,
+    pub start_date_max: Option<DateTime<Utc>>,
+    pub end_date_min: Option<DateTime<Utc>>,
+    pub end_date_max: Option<DateTime<Utc>>,
+}
+
+impl MarketFilter {
+    /// Encodes the filter as query pairs; list fields repeat their key once per value.
+    pub fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        push_opt(&mut query, "limit", self.limit);
+        push_opt(&mut query, "offset", self.offset);
+        push_opt(&mut query, "order", self.order.as_ref());
+        push_opt(&mut query, "ascending", self.ascending);
+        push_all(&mut query, "id", &self.ids);
+        push_all(&mut query, "slug", &self.slugs);
+        push_all(&mut query, "clob_token_ids", &self.clob_token_ids);
+        push_all(&mut query, "condition_ids", &self.condition_ids);
+        push_opt(&mut query, "tag_id", self.tag_id.as_ref());
+        push_opt(&mut query, "related_tags", self.related_tags);
+        push_opt(&mut query, "active", self.active);
+        push_opt(&mut query, "closed", self.closed);
+        push_opt(&mut query, "archived", self.archived);
+        push_opt(&mut query, "liquidity_num_min", self.liquidity_num_min);
+        push_opt(&mut query, "liquidity_num_max", self.liquidity_num_max);
+        push_opt(&mut query, "volume_num_min", self.volume_num_min);
+        push_opt(&mut query, "volume_num_max", self.volume_num_max);
+        push_date(&mut query, "start_date_min", self.start_date_min);
+        push_date(&mut query, "start_date_max", self.start_date_max);
+        push_date(&mut query, "end_date_min", self.end_date_min);
+        push_date(&mut query, "end_date_max", self.end_date_max);
+        query
+    }
+
+    /// Restricts the listing to markets tagged with `tag`.
+    pub fn with_tag(self, tag: &Tag) -> Self {
+        Self { tag_id: Some(tag.id.clone()), ..self }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct MarketEndpoint {
@@ -20,14 +88,80 @@ impl MarketEndpoint {
         let request = self.client.get(&url);
 
         let response = request.send().await?;
-        let status = response.status();
+        into_result(response).await
+    }
 
-        if status.is_success() {
-            response.json().await.map_err(|e| e.into())
-        } else {
-            let message = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+    /// One page of markets matching `filter`.
+    pub async fn list_markets(&self, filter: &MarketFilter) -> Result<Vec<Market>, Error> {
+        let url = format!("{}/markets", self.api_base);
+        let request = self.client.get(&url).query(&filter.to_query());
+
+        let response = request.send().await?;
+        into_result(response).await
+    }
+
+    /// Every market matching `filter`, fetched page by page (`filter.limit` per request)
+    /// starting at `filter.offset`.
+    pub fn list_markets_stream(
+        &self,
+        filter: MarketFilter,
+    ) -> impl Stream<Item = Result<Market, Error>> + '_ {
+        let limit = filter.limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        let offset = filter.offset.unwrap_or_default();
+        paginate(limit, offset, move |limit, offset| {
+            let filter =
+                MarketFilter { limit: Some(limit), offset: Some(offset), ..filter.clone() };
+            async move { self.list_markets(&filter).await }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_filter_sends_nothing() {
+        assert!(MarketFilter::default().to_query().is_empty());
+    }
+
+    #[test]
+    fn filter_repeats_list_keys() {
+        let filter = MarketFilter {
+            limit: Some(50),
+            order: Some("volume".into()),
+            ascending: Some(false),
+            slugs: vec!["a".into(), "b".into()],
+            clob_token_ids: vec!["123".parse().unwrap()],
+            condition_ids: vec![format!("0x{:064x}", 1).parse().unwrap()],
+            closed: Some(true),
+            volume_num_min: Some(Decimal::new(15, 1)),
+            end_date_max: DateTime::from_timestamp(1_700_000_000, 0),
+            ..Default::default()
+        };
+        assert_eq!(
+            filter.to_query(),
+            [
+                ("limit", "50".to_string()),
+                ("order", "volume".to_string()),
+                ("ascending", "false".to_string()),
+                ("slug", "a".to_string()),
+                ("slug", "b".to_string()),
+                ("clob_token_ids", "123".to_string()),
+                ("condition_ids", format!("0x{:064x}", 1)),
+                ("closed", "true".to_string()),
+                ("volume_num_min", "1.5".to_string()),
+                ("end_date_max", "2023-11-14T22:13:20+00:00".to_string()),
+            ]
+        );
+    }
 
-            Err(Error::msg(format!("API error: {} ({})", message, status.as_u16())))
-        }
+    #[test]
+    fn with_tag_sets_tag_id() {
+        let tag = Tag { id: "21".into(), ..Default::default() };
+        assert_eq!(
+            MarketFilter::default().with_tag(&tag).to_query(),
+            [("tag_id", "21".to_string())]
+        );
     }
 }
@@ -0,0 +1,410 @@
+use crate::{
+    event::{EventEndpoint, EventFilter},
+    types::{Event, Market, MarketOutcome},
+};
+use anyhow::{Error, Result};
+use chrono::{DateTime, Utc};
+use clob::{ConditionId, MarketSlug, TokenId};
+use futures::TryStreamExt;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fs, path::Path, time::Duration};
+
+/// Position of a market in the registry: `(event index, market index)`.
+type MarketPos = (usize, usize);
+
+/// Events as loaded from Gamma, the part of the registry written to disk.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RegistrySnapshot {
+    pub loaded_at: Option<DateTime<Utc>>,
+    pub events: Vec<Event>,
+}
+
+/// In-memory index of Gamma events and markets, resolving any of slug, condition id, token id
+/// and outcome name to the others.
+///
+/// The index is rebuilt from Gamma by [`MarketRegistry::refresh`] once older than its TTL, and can
+/// be saved to and restored from disk to skip the initial load.
+#[derive(Debug, Clone)]
+pub struct MarketRegistry {
+    endpoint: EventEndpoint,
+    filter: EventFilter,
+    ttl: Duration,
+    snapshot: RegistrySnapshot,
+    by_slug: HashMap<MarketSlug, MarketPos>,
+    by_condition_id: HashMap<ConditionId, MarketPos>,
+    by_token_id: HashMap<TokenId, (MarketPos, usize)>,
+    by_neg_risk_market_id: HashMap<String, Vec<MarketPos>>,
+}
+
+impl MarketRegistry {
+    /// Empty registry loading the events matching `filter` from the Gamma API at `api_base`.
+    pub fn new(api_base: &str, filter: EventFilter, ttl: Duration) -> Result<Self> {
+        Ok(Self {
+            endpoint: EventEndpoint::new(api_base)?,
+            filter,
+            ttl,
+            snapshot: RegistrySnapshot::default(),
+            by_slug: HashMap::new(),
+            by_condition_id: HashMap::new(),
+            by_token_id: HashMap::new(),
+            by_neg_risk_market_id: HashMap::new(),
+        })
+    }
+}
+
+impl MarketRegistry {
+    pub const fn loaded_at(&self) -> Option<DateTime<Utc>> {
+        self.snapshot.loaded_at
+    }
+
+    /// Whether the registry was never loaded or was loaded more than the TTL ago.
+    pub fn is_stale(&self) -> bool {
+        self.snapshot.loaded_at.is_none_or(|loaded_at| {
+            (Utc::now() - loaded_at).to_std().is_ok_and(|age| age >= self.ttl)
+        })
+    }
+
+    /// Reloads every event matching the registry's filter and rebuilds the index.
+    pub async fn refresh(&mut self) -> Result<()> {
+        let events: Vec<Event> =
+            self.endpoint.list_events_stream(self.filter.clone()).try_collect().await?;
+        self.replace(RegistrySnapshot { loaded_at: Some(Utc::now()), events });
+        Ok(())
+    }
+
+    /// Refreshes the registry if it is stale.
+    pub async fn ensure_fresh(&mut self) -> Result<()> {
+        if self.is_stale() {
+            self.refresh().await?;
+        }
+        Ok(())
+    }
+
+    pub const fn snapshot(&self) -> &RegistrySnapshot {
+        &self.snapshot
+    }
+
+    /// Writes the loaded events to `path` as JSON.
+    pub fn save_snapshot(&self, path: impl AsRef<Path>) -> Result<()> {
+        let json = serde_json::to_vec(&self.snapshot)?;
+        fs::write(path.as_ref(), json)
+            .map_err(|e| Error::msg(format!("Failed to write {}: {}", path.as_ref().display(), e)))
+    }
+
+    /// Replaces the registry's content with the snapshot at `path`. The snapshot keeps its load
+    /// time, so an old snapshot is refreshed by the next [`MarketRegistry::ensure_fresh`].
+    pub fn load_snapshot(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        let json = fs::read(path.as_ref()).map_err(|e| {
+            Error::msg(format!("Failed to read {}: {}", path.as_ref().display(), e))
+        })?;
+        self.replace(serde_json::from_slice(&json)?);
+        Ok(())
+    }
+
+    fn replace(&mut self, snapshot: RegistrySnapshot) {
+        self.by_slug.clear();
+        self.by_condition_id.clear();
+        self.by_token_id.clear();
+        self.by_neg_risk_market_id.clear();
+
+        for (event_index, event) in snapshot.events.iter().enumerate() {
+            for (market_index, market) in event.markets.iter().enumerate() {
+                let pos = (event_index, market_index);
+                if let Some(slug) = &market.slug {
+                    self.by_slug.insert(slug.clone(), pos);
+                }
+                if let Some(condition_id) = market.condition_id {
+                    self.by_condition_id.insert(condition_id, pos);
+                }
+                for (outcome_index, token_id) in market.clob_token_ids.iter().enumerate() {
+                    self.by_token_id.insert(*token_id, (pos, outcome_index));
+                }
+                if let Some(group) = neg_risk_group(event, market) {
+                    self.by_neg_risk_market_id.entry(group.to_string()).or_default().push(pos);
+                }
+            }
+        }
+        self.snapshot = snapshot;
+    }
+
+    fn market_at(&self, (event_index, market_index): MarketPos) -> &Market {
+        &self.snapshot.events[event_index].markets[market_index]
+    }
+}
+
+impl MarketRegistry {
+    pub fn events(&self) -> &[Event] {
+        &self.snapshot.events
+    }
+
+    pub fn markets(&self) -> impl Iterator<Item = &Market> {
+        self.snapshot.events.iter().flat_map(|event| &event.markets)
+    }
+
+    pub fn market_by_slug(&self, slug: &str) -> Option<&Market> {
+        self.by_slug.get(slug).map(|&pos| self.market_at(pos))
+    }
+
+    pub fn market_by_condition_id(&self, condition_id: ConditionId) -> Option<&Market> {
+        self.by_condition_id.get(&condition_id).map(|&pos| self.market_at(pos))
+    }
+
+    pub fn market_by_token_id(&self, token_id: TokenId) -> Option<&Market> {
+        self.by_token_id.get(&token_id).map(|&(pos, _)| self.market_at(pos))
+    }
+
+    /// Market referenced by a slug, condition id or token id.
+    pub fn resolve(&self, reference: &str) -> Option<&Market> {
+        self.market_by_slug(reference)
+            .or_else(|| self.market_by_condition_id(reference.parse().ok()?))
+            .or_else(|| self.market_by_token_id(reference.parse().ok()?))
+    }
+
+    /// Event the market with `condition_id` belongs to.
+    pub fn event_of(&self, condition_id: ConditionId) -> Option<&Event> {
+        self.by_condition_id
+            .get(&condition_id)
+            .map(|&(event_index, _)| &self.snapshot.events[event_index])
+    }
+
+    /// Outcome (name, index and price) the token `token_id` pays out on.
+    pub fn outcome_of_token(&self, token_id: TokenId) -> Option<MarketOutcome<'_>> {
+        let &(pos, outcome_index) = self.by_token_id.get(&token_id)?;
+        self.market_at(pos).outcome(outcome_index)
+    }
+
+    /// Token of the outcome named `outcome` (case-insensitive) in the market `reference`.
+    pub fn token_id_for_outcome(&self, reference: &str, outcome: &str) -> Option<TokenId> {
+        let market = self.resolve(reference)?;
+        let index = market.outcomes.iter().position(|name| name.eq_ignore_ascii_case(outcome))?;
+        market.clob_token_ids.get(index).copied()
+    }
+
+    /// The other markets of the neg-risk event the market with `condition_id` belongs to.
+    pub fn neg_risk_siblings(&self, condition_id: ConditionId) -> Vec<&Market> {
+        self.by_condition_id
+            .get(&condition_id)
+            .map(|&pos| self.siblings_at(pos).collect())
+            .unwrap_or_default()
+    }
+
+    /// Tokens of the same outcome (e.g. every other "Yes") in the neg-risk siblings of the
+    /// market `token_id` belongs to.
+    pub fn neg_risk_sibling_tokens(&self, token_id: TokenId) -> Vec<TokenId> {
+        let Some(&(pos, outcome_index)) = self.by_token_id.get(&token_id) else {
+            return Vec::new();
+        };
+
+        self.siblings_at(pos)
+            .filter_map(|sibling| sibling.clob_token_ids.get(outcome_index).copied())
+            .collect()
+    }
+
+    fn siblings_at(&self, pos: MarketPos) -> impl Iterator<Item = &Market> {
+        let (event_index, market_index) = pos;
+        let event = &self.snapshot.events[event_index];
+        neg_risk_group(event, &event.markets[market_index])
+            .and_then(|group| self.by_neg_risk_market_id.get(group))
+            .into_iter()
+            .flatten()
+            .filter(move |&&sibling| sibling != pos)
+            .map(|&sibling| self.market_at(sibling))
+    }
+}
+
+/// Neg-risk market id shared by the markets of a neg-risk event; embedded markets do not always
+/// repeat the event's flags.
+fn neg_risk_group<'a>(event: &'a Event, market: &'a Market) -> Option<&'a str> {
+    if !(market.neg_risk || event.neg_risk) {
+        return None;
+    }
+    market.neg_risk_market_id.as_deref().or(event.neg_risk_market_id.as_deref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::Decimal;
+    use std::{env, process};
+
+    fn condition_id(n: u8) -> ConditionId {
+        format!("0x{n:064x}").parse().unwrap()
+    }
+
+    /// Token `n * 10 + outcome` of market `n`.
+    fn token_id(n: u8, outcome: u8) -> TokenId {
+        (u32::from(n) * 10 + u32::from(outcome)).to_string().parse().unwrap()
+    }
+
+    fn market(n: u8, slug: &str, neg_risk: bool, neg_risk_market_id: Option<&str>) -> Market {
+        Market {
+            id: n.to_string(),
+            condition_id: Some(condition_id(n)),
+            slug: Some(slug.into()),
+            outcomes: vec!["Yes".into(), "No".into()],
+            outcome_prices: vec![Decimal::new(60, 2), Decimal::new(40, 2)],
+            clob_token_ids: vec![token_id(n, 1), token_id(n, 2)],
+            neg_risk,
+            neg_risk_market_id: neg_risk_market_id.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    /// A neg-risk event whose second market does not repeat the event's neg-risk flags, and a
+    /// plain binary event.
+    fn snapshot() -> RegistrySnapshot {
+        let election = Event {
+            id: "1".into(),
+            neg_risk: true,
+            neg_risk_market_id: Some("0xgroup".into()),
+            markets: vec![
+                market(1, "trump", true, Some("0xgroup")),
+                market(2, "harris", false, None),
+                market(3, "other", true, Some("0xgroup")),
+            ],
+            ..Default::default()
+        };
+        let weather = Event {
+            id: "2".into(),
+            markets: vec![market(4, "rain", false, None)],
+            ..Default::default()
+        };
+        RegistrySnapshot { loaded_at: Some(Utc::now()), events: vec![election, weather] }
+    }
+
+    fn registry(ttl: Duration) -> MarketRegistry {
+        let mut registry =
+            MarketRegistry::new("http://127.0.0.1:9", EventFilter::default(), ttl).unwrap();
+        registry.replace(snapshot());
+        registry
+    }
+
+    fn ids(markets: &[&Market]) -> Vec<String> {
+        markets.iter().map(|market| market.id.clone()).collect()
+    }
+
+    #[test]
+    fn indexes_every_market() {
+        let registry = registry(Duration::from_secs(60));
+
+        assert_eq!(registry.markets().count(), 4);
+        assert_eq!(registry.by_slug.len(), 4);
+        assert_eq!(registry.by_condition_id.len(), 4);
+        assert_eq!(registry.by_token_id.len(), 8);
+        assert_eq!(registry.by_neg_risk_market_id["0xgroup"], vec![(0, 0), (0, 1), (0, 2)]);
+    }
+
+    #[test]
+    fn looks_up_by_slug_condition_and_token() {
+        let registry = registry(Duration::from_secs(60));
+
+        assert_eq!(registry.market_by_slug("harris").unwrap().id, "2");
+        assert_eq!(registry.market_by_condition_id(condition_id(4)).unwrap().id, "4");
+        assert_eq!(registry.market_by_token_id(token_id(3, 2)).unwrap().id, "3");
+        assert!(registry.market_by_slug("missing").is_none());
+        assert!(registry.market_by_condition_id(condition_id(9)).is_none());
+
+        assert_eq!(registry.resolve("rain").unwrap().id, "4");
+        assert_eq!(registry.resolve(&condition_id(1).to_string()).unwrap().id, "1");
+        assert_eq!(registry.resolve("22").unwrap().id, "2");
+        assert!(registry.resolve("nothing").is_none());
+
+        assert_eq!(registry.event_of(condition_id(4)).unwrap().id, "2");
+    }
+
+    #[test]
+    fn maps_tokens_to_outcomes() {
+        let registry = registry(Duration::from_secs(60));
+
+        let outcome = registry.outcome_of_token(token_id(1, 2)).unwrap();
+        assert_eq!(outcome.index, 1);
+        assert_eq!(outcome.name, "No");
+        assert_eq!(outcome.price, Some(Decimal::new(40, 2)));
+
+        assert_eq!(registry.token_id_for_outcome("trump", "yes"), Some(token_id(1, 1)));
+        assert_eq!(registry.token_id_for_outcome("rain", "NO"), Some(token_id(4, 2)));
+        assert_eq!(registry.token_id_for_outcome("rain", "Maybe"), None);
+    }
+
+    #[test]
+    fn finds_neg_risk_siblings() {
+        let registry = registry(Duration::from_secs(60));
+
+        assert_eq!(ids(&registry.neg_risk_siblings(condition_id(1))), ["2", "3"]);
+        // Falls back to the event's negRiskMarketID
+        assert_eq!(ids(&registry.neg_risk_siblings(condition_id(2))), ["1", "3"]);
+        assert!(registry.neg_risk_siblings(condition_id(4)).is_empty());
+
+        assert_eq!(
+            registry.neg_risk_sibling_tokens(token_id(2, 1)),
+            [token_id(1, 1), token_id(3, 1)]
+        );
+        assert_eq!(
+            registry.neg_risk_sibling_tokens(token_id(3, 2)),
+            [token_id(1, 2), token_id(2, 2)]
+        );
+        assert!(registry.neg_risk_sibling_tokens(token_id(4, 1)).is_empty());
+    }
+
+    #[test]
+    fn stale_after_ttl() {
+        let mut registry = registry(Duration::from_secs(60));
+        assert!(!registry.is_stale());
+
+        registry.snapshot.loaded_at = Some(Utc::now() - chrono::Duration::seconds(61));
+        assert!(registry.is_stale());
+
+        registry.snapshot.loaded_at = None;
+        assert!(registry.is_stale());
+    }
+
+    #[test]
+    fn snapshot_round_trips_through_disk() {
+        let registry = registry(Duration::from_secs(60));
+        let path = env::temp_dir().join(format!("registry-snapshot-{}.json", process::id()));
+        registry.save_snapshot(&path).unwrap();
+
+        let mut restored = MarketRegistry::new(
+            "http://127.0.0.1:9",
+            EventFilter::default(),
+            Duration::from_secs(60),
+        )
+        .unwrap();
+        restored.load_snapshot(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(restored.loaded_at(), registry.loaded_at());
+        assert_eq!(restored.markets().count(), 4);
+        assert_eq!(restored.market_by_token_id(token_id(4, 1)).unwrap().slug, Some("rain".into()));
+        assert_eq!(ids(&restored.neg_risk_siblings(condition_id(2))), ["1", "3"]);
+        assert_eq!(restored.token_id_for_outcome("trump", "No"), Some(token_id(1, 2)));
+    }
+
+    #[test]
+    fn indexes_markets_without_condition_id() {
+        let mut snapshot = snapshot();
+        let mut pending = market(5, "pending", true, Some("0xgroup"));
+        pending.condition_id = None;
+        snapshot.events[0].markets.push(pending);
+
+        let mut registry = registry(Duration::from_secs(60));
+        registry.replace(snapshot);
+
+        assert_eq!(registry.by_condition_id.len(), 4);
+        assert_eq!(registry.market_by_slug("pending").unwrap().id, "5");
+        assert_eq!(registry.market_by_token_id(token_id(5, 1)).unwrap().id, "5");
+        assert_eq!(ids(&registry.neg_risk_siblings(condition_id(1))), ["2", "3", "5"]);
+        assert_eq!(
+            registry.neg_risk_sibling_tokens(token_id(5, 1)),
+            [token_id(1, 1), token_id(2, 1), token_id(3, 1)]
+        );
+    }
+
+    #[test]
+    fn loading_a_missing_snapshot_fails() {
+        let mut registry = registry(Duration::from_secs(60));
+        let path = env::temp_dir().join("registry-snapshot-does-not-exist.json");
+        assert!(registry.load_snapshot(path).is_err());
+        assert_eq!(registry.markets().count(), 4);
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{
+    types::SearchResults,
+    utils::{into_result, push_all, push_opt},
+};
+use anyhow::{Error, Result};
+
+/// Optional parameters of Gamma's `/public-search`. Unset fields are not sent.
+#[derive(Debug, Clone, Default)]
+pub struct SearchFilter {
+    /// Maximum results per type (events, tags, profiles)
+    pub limit_per_type: Option<u64>,
+    /// 1-based page of results
+    pub page: Option<u64>,
+    /// `active`, `closed` or `all`
+    pub events_status: Option<String>,
+    /// Tag slugs the matched events must carry
+    pub tags: Vec<String>,
+    pub keep_closed_markets: Option<bool>,
+    pub sort: Option<String>,
+    pub ascending: Option<bool>,
+    pub search_tags: Option<bool>,
+    pub search_profiles: Option<bool>,
+}
+
+impl SearchFilter {
+    pub fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        push_opt(&mut query, "limit_per_type", self.limit_per_type);
+        push_opt(&mut query, "page", self.page);
+        push_opt(&mut query, "events_status", self.events_status.as_ref());
+        push_all(&mut query, "events_tag", &self.tags);
+        push_opt(&mut query, "keep_closed_markets", self.keep_closed_markets.map(u8::from));
+        push_opt(&mut query, "sort", self.sort.as_ref());
+        push_opt(&mut query, "ascending", self.ascending);
+        push_opt(&mut query, "search_tags", self.search_tags);
+        push_opt(&mut query, "search_profiles", self.search_profiles);
+        query
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchEndpoint {
+    api_base: String,
+    client: reqwest::Client,
+}
+
+impl SearchEndpoint {
+    pub fn new(api_base: &str) -> Result<Self> {
+        let client = reqwest::Client::builder().build()?;
+        Ok(Self { api_base: api_base.to_string(), client })
+    }
+}
+
+impl SearchEndpoint {
+    /// Full-text search over events (with their markets), tags and profiles.
+    pub async fn public_search(
+        &self,
+        query: &str,
+        filter: &SearchFilter,
+    ) -> Result<SearchResults, Error> {
+        let url = format!("{}/public-search", self.api_base);
+        let request = self.client.get(&url).query(&[("q", query)]).query(&filter.to_query());
+
+        let response = request.send().await?;
+        into_result(response).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_to_query() {
+        assert!(SearchFilter::default().to_query().is_empty());
+
+        let filter = SearchFilter {
+            limit_per_type: Some(5),
+            page: Some(2),
+            events_status: Some("active".into()),
+            tags: vec!["politics".into(), "us".into()],
+            keep_closed_markets: Some(true),
+            search_profiles: Some(false),
+            ..Default::default()
+        };
+        assert_eq!(
+            filter.to_query(),
+            [
+                ("limit_per_type", "5".to_string()),
+                ("page", "2".to_string()),
+                ("events_status", "active".to_string()),
+                ("events_tag", "politics".to_string()),
+                ("events_tag", "us".to_string()),
+                ("keep_closed_markets", "1".to_string()),
+                ("search_profiles", "false".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{
+    types::Series,
+    utils::{DEFAULT_PAGE_LIMIT, into_result, paginate, push_all, push_opt},
+};
+use anyhow::{Error, Result};
+use futures::Stream;
+
+/// Query parameters of Gamma's `/series` listing. Unset fields are not sent.
+#[derive(Debug, Clone, Default)]
+pub struct SeriesFilter {
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+    pub order: Option<String>,
+    pub ascending: Option<bool>,
+    pub slugs: Vec<String>,
+    pub closed: Option<bool>,
+    pub recurrence: Option<String>,
+}
+
+impl SeriesFilter {
+    /// Encodes the filter as query pairs; list fields repeat their key once per value.
+    pub fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        push_opt(&mut query, "limit", self.limit);
+        push_opt(&mut query, "offset", self.offset);
+        push_opt(&mut query, "order", self.order.as_ref());
+        push_opt(&mut query, "ascending", self.ascending);
+        push_all(&mut query, "slug", &self.slugs);
+        push_opt(&mut query, "closed", self.closed);
+        push_opt(&mut query, "recurrence", self.recurrence.as_ref());
+        query
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SeriesEndpoint {
+    api_base: String,
+    client: reqwest::Client,
+}
+
+impl SeriesEndpoint {
+    pub fn new(api_base: &str) -> Result<Self> {
+        let client = reqwest::Client::builder().build()?;
+        Ok(Self { api_base: api_base.to_string(), client })
+    }
+}
+
+impl SeriesEndpoint {
+    pub async fn get_series_by_id(&self, id: &str) -> Result<Series, Error> {
+        let url = format!("{}/series/{}", self.api_base, id);
+        let request = self.client.get(&url);
+
+        let response = request.send().await?;
+        into_result(response).await
+    }
+
+    /// One page of series matching `filter`.
+    pub async fn list_series(&self, filter: &SeriesFilter) -> Result<Vec<Series>, Error> {
+        let url = format!("{}/series", self.api_base);
+        let request = self.client.get(&url).query(&filter.to_query());
+
+        let response = request.send().await?;
+        into_result(response).await
+    }
+
+    /// Every series matching `filter`, fetched page by page.
+    pub fn list_series_stream(
+        &self,
+        filter: SeriesFilter,
+    ) -> impl Stream<Item = Result<Series, Error>> + '_ {
+        let limit = filter.limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        let offset = filter.offset.unwrap_or_default();
+        paginate(limit, offset, move |limit, offset| {
+            let filter =
+                SeriesFilter { limit: Some(limit), offset: Some(offset), ..filter.clone() };
+            async move { self.list_series(&filter).await }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_to_query() {
+        assert!(SeriesFilter::default().to_query().is_empty());
+
+        let filter = SeriesFilter {
+            limit: Some(10),
+            slugs: vec!["nba".into(), "nfl".into()],
+            closed: Some(false),
+            recurrence: Some("daily".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            filter.to_query(),
+            [
+                ("limit", "10".to_string()),
+                ("slug", "nba".to_string()),
+                ("slug", "nfl".to_string()),
+                ("closed", "false".to_string()),
+                ("recurrence", "daily".to_string()),
+            ]
+        );
+    }
+}
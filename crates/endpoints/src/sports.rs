@@ -0,0 +1,93 @@
+use crate::{
+    types::{Sport, Team},
+    utils::{into_result, push_all, push_opt},
+};
+use anyhow::{Error, Result};
+
+/// Query parameters of Gamma's `/teams` listing. Unset fields are not sent.
+#[derive(Debug, Clone, Default)]
+pub struct TeamFilter {
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+    pub order: Option<String>,
+    pub ascending: Option<bool>,
+    pub leagues: Vec<String>,
+    pub names: Vec<String>,
+    pub abbreviations: Vec<String>,
+}
+
+impl TeamFilter {
+    /// Encodes the filter as query pairs; list fields repeat their key once per value.
+    pub fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        push_opt(&mut query, "limit", self.limit);
+        push_opt(&mut query, "offset", self.offset);
+        push_opt(&mut query, "order", self.order.as_ref());
+        push_opt(&mut query, "ascending", self.ascending);
+        push_all(&mut query, "league", &self.leagues);
+        push_all(&mut query, "name", &self.names);
+        push_all(&mut query, "abbreviation", &self.abbreviations);
+        query
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SportsEndpoint {
+    api_base: String,
+    client: reqwest::Client,
+}
+
+impl SportsEndpoint {
+    pub fn new(api_base: &str) -> Result<Self> {
+        let client = reqwest::Client::builder().build()?;
+        Ok(Self { api_base: api_base.to_string(), client })
+    }
+}
+
+impl SportsEndpoint {
+    pub async fn get_sports(&self) -> Result<Vec<Sport>, Error> {
+        let url = format!("{}/sports", self.api_base);
+        let request = self.client.get(&url);
+
+        let response = request.send().await?;
+        into_result(response).await
+    }
+
+    pub async fn list_teams(&self, filter: &TeamFilter) -> Result<Vec<Team>, Error> {
+        let url = format!("{}/teams", self.api_base);
+        let request = self.client.get(&url).query(&filter.to_query());
+
+        let response = request.send().await?;
+        into_result(response).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_to_query() {
+        assert!(TeamFilter::default().to_query().is_empty());
+
+        let filter = TeamFilter {
+            order: Some("name".into()),
+            ascending: Some(true),
+            leagues: vec!["nba".into()],
+            names: vec!["Lakers".into()],
+            abbreviations: vec!["LAL".into(), "BOS".into()],
+            ..Default::default()
+        };
+        assert_eq!(
+            filter.to_query(),
+            [
+                ("order", "name".to_string()),
+                ("ascending", "true".to_string()),
+                ("league", "nba".to_string()),
+                ("name", "Lakers".to_string()),
+                ("abbreviation", "LAL".to_string()),
+                ("abbreviation", "BOS".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{
+    types::Tag,
+    utils::{DEFAULT_PAGE_LIMIT, into_result, paginate, push_opt},
+};
+use anyhow::{Error, Result};
+use futures::Stream;
+
+/// Query parameters of Gamma's `/tags` listing. Unset fields are not sent.
+#[derive(Debug, Clone, Default)]
+pub struct TagFilter {
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+    pub order: Option<String>,
+    pub ascending: Option<bool>,
+    pub is_carousel: Option<bool>,
+}
+
+impl TagFilter {
+    pub fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        push_opt(&mut query, "limit", self.limit);
+        push_opt(&mut query, "offset", self.offset);
+        push_opt(&mut query, "order", self.order.as_ref());
+        push_opt(&mut query, "ascending", self.ascending);
+        push_opt(&mut query, "is_carousel", self.is_carousel);
+        query
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TagEndpoint {
+    api_base: String,
+    client: reqwest::Client,
+}
+
+impl TagEndpoint {
+    pub fn new(api_base: &str) -> Result<Self> {
+        let client = reqwest::Client::builder().build()?;
+        Ok(Self { api_base: api_base.to_string(), client })
+    }
+}
+
+impl TagEndpoint {
+    pub async fn get_tag_by_id(&self, id: &str) -> Result<Tag, Error> {
+        self.get(&format!("tags/{}", id)).await
+    }
+
+    pub async fn get_tag_by_slug(&self, slug: &str) -> Result<Tag, Error> {
+        self.get(&format!("tags/slug/{}", slug)).await
+    }
+
+    /// Tags related to the tag `id`, e.g. the leagues of a sport.
+    pub async fn get_related_tags(&self, id: &str) -> Result<Vec<Tag>, Error> {
+        self.get(&format!("tags/{}/related-tags/tags", id)).await
+    }
+
+    pub async fn get_related_tags_by_slug(&self, slug: &str) -> Result<Vec<Tag>, Error> {
+        self.get(&format!("tags/slug/{}/related-tags/tags", slug)).await
+    }
+
+    /// One page of tags matching `filter`.
+    pub async fn list_tags(&self, filter: &TagFilter) -> Result<Vec<Tag>, Error> {
+        let url = format!("{}/tags", self.api_base);
+        let request = self.client.get(&url).query(&filter.to_query());
+
+        let response = request.send().await?;
+        into_result(response).await
+    }
+
+    /// Every tag matching `filter`, fetched page by page.
+    pub fn list_tags_stream(
+        &self,
+        filter: TagFilter,
+    ) -> impl Stream<Item = Result<Tag, Error>> + '_ {
+        let limit = filter.limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        let offset = filter.offset.unwrap_or_default();
+        paginate(limit, offset, move |limit, offset| {
+            let filter = TagFilter { limit: Some(limit), offset: Some(offset), ..filter.clone() };
+            async move { self.list_tags(&filter).await }
+        })
+    }
+
+    async fn get<T: serde::de::DeserializeOwned>(&self, path: &str) -> Result<T, Error> {
+        let url = format!("{}/{}", self.api_base, path);
+        let request = self.client.get(&url);
+
+        let response = request.send().await?;
+        into_result(response).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_to_query() {
+        assert!(TagFilter::default().to_query().is_empty());
+
+        let filter = TagFilter {
+            limit: Some(20),
+            offset: Some(40),
+            is_carousel: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(
+            filter.to_query(),
+            [
+                ("limit", "20".to_string()),
+                ("offset", "40".to_string()),
+                ("is_carousel", "true".to_string()),
+            ]
+        );
+    }
+}
@@ -1,38 +1,650 @@
 use crate::utils::{
-    deserialize_decimal_vec_from_json_string, deserialize_string_vec_from_json_string,
+    deserialize_decimal_vec_from_json_string, deserialize_option_decimal,
+    deserialize_option_from_str, deserialize_string_vec_from_json_string,
+    deserialize_vec_from_json_string, serialize_vec_as_json_string,
 };
+use anyhow::{Error, Result};
 use chrono::{DateTime, Utc};
+use clob::{ConditionId, Ctf, MarketSlug, TickSize, TokenId, deserialize_decimal};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
 pub struct Market {
     pub id: String,
-    #[serde(rename = "conditionId")]
-    pub condition_id: String,
-    pub slug: Option<String>,
-    #[serde(rename = "outcomePrices")]
+    /// `None` when Gamma sends an empty id, e.g. for markets not yet deployed
+    #[serde(rename = "conditionId", default, deserialize_with = "deserialize_option_from_str")]
+    pub condition_id: Option<ConditionId>,
+    #[serde(rename = "questionID")]
+    pub question_id: Option<String>,
+    pub slug: Option<MarketSlug>,
+    pub question: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_string_vec_from_json_string")]
+    #[serde(serialize_with = "serialize_vec_as_json_string")]
+    pub outcomes: Vec<String>,
+    #[serde(rename = "outcomePrices", default)]
     #[serde(deserialize_with = "deserialize_decimal_vec_from_json_string")]
+    #[serde(serialize_with = "serialize_vec_as_json_string")]
     pub outcome_prices: Vec<Decimal>,
     #[serde(rename = "startDate")]
     pub start_date: Option<DateTime<Utc>>,
     #[serde(rename = "endDate")]
     pub end_date: Option<DateTime<Utc>>,
-    #[serde(rename = "clobTokenIds")]
+    #[serde(rename = "clobTokenIds", default)]
+    #[serde(deserialize_with = "deserialize_vec_from_json_string")]
+    #[serde(serialize_with = "serialize_vec_as_json_string")]
+    pub clob_token_ids: Vec<TokenId>,
+    pub active: Option<bool>,
+    pub closed: Option<bool>,
+    pub archived: Option<bool>,
+    #[serde(rename = "enableOrderBook", default)]
+    pub enable_order_book: bool,
+    #[serde(rename = "acceptingOrders", default)]
+    pub accepting_orders: bool,
+    #[serde(rename = "negRisk", default)]
+    pub neg_risk: bool,
+    #[serde(rename = "negRiskMarketID")]
+    pub neg_risk_market_id: Option<String>,
+    #[serde(rename = "negRiskRequestID")]
+    pub neg_risk_request_id: Option<String>,
+    #[serde(rename = "orderPriceMinTickSize", default)]
+    pub order_price_min_tick_size: Option<TickSize>,
+    #[serde(rename = "orderMinSize", default, deserialize_with = "deserialize_option_decimal")]
+    pub order_min_size: Option<Decimal>,
+    #[serde(rename = "volumeNum", default, deserialize_with = "deserialize_option_decimal")]
+    pub volume: Option<Decimal>,
+    #[serde(rename = "volume24hr", default, deserialize_with = "deserialize_option_decimal")]
+    pub volume_24hr: Option<Decimal>,
+    #[serde(rename = "liquidityNum", default, deserialize_with = "deserialize_option_decimal")]
+    pub liquidity: Option<Decimal>,
+    #[serde(rename = "bestBid", default, deserialize_with = "deserialize_option_decimal")]
+    pub best_bid: Option<Decimal>,
+    #[serde(rename = "bestAsk", default, deserialize_with = "deserialize_option_decimal")]
+    pub best_ask: Option<Decimal>,
+    #[serde(default, deserialize_with = "deserialize_option_decimal")]
+    pub spread: Option<Decimal>,
+    #[serde(rename = "lastTradePrice", default, deserialize_with = "deserialize_option_decimal")]
+    pub last_trade_price: Option<Decimal>,
+    /// Minimum order size, in shares, for liquidity rewards
+    #[serde(rename = "rewardsMinSize", default, deserialize_with = "deserialize_option_decimal")]
+    pub rewards_min_size: Option<Decimal>,
+    /// Maximum distance from the midpoint, in cents, for liquidity rewards
+    #[serde(rename = "rewardsMaxSpread", default, deserialize_with = "deserialize_option_decimal")]
+    pub rewards_max_spread: Option<Decimal>,
+    #[serde(rename = "clobRewards", default)]
+    pub clob_rewards: Vec<ClobReward>,
+    #[serde(rename = "resolutionSource")]
+    pub resolution_source: Option<String>,
+    #[serde(rename = "resolvedBy")]
+    pub resolved_by: Option<String>,
+    #[serde(rename = "umaBond", default, deserialize_with = "deserialize_option_decimal")]
+    pub uma_bond: Option<Decimal>,
+    #[serde(rename = "umaReward", default, deserialize_with = "deserialize_option_decimal")]
+    pub uma_reward: Option<Decimal>,
+    #[serde(rename = "umaResolutionStatus")]
+    pub uma_resolution_status: Option<String>,
+    #[serde(rename = "umaResolutionStatuses", default)]
     #[serde(deserialize_with = "deserialize_string_vec_from_json_string")]
-    pub clob_token_ids: Vec<String>,
-}
-
-impl Default for Market {
-    fn default() -> Self {
-        Self {
-            id: String::new(),
-            condition_id: String::new(),
-            slug: None,
-            outcome_prices: Vec::new(),
-            start_date: None,
-            end_date: None,
-            clob_token_ids: Vec::new(),
+    #[serde(serialize_with = "serialize_vec_as_json_string")]
+    pub uma_resolution_statuses: Vec<String>,
+}
+
+/// One outcome of a market with its CLOB token and last known price.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MarketOutcome<'a> {
+    pub index: usize,
+    pub name: &'a str,
+    pub token_id: Option<TokenId>,
+    pub price: Option<Decimal>,
+}
+
+impl Market {
+    /// Outcome at `index` (0 is the first, usually "Yes"), `None` past the last outcome.
+    pub fn outcome(&self, index: usize) -> Option<MarketOutcome<'_>> {
+        let name = self.outcomes.get(index)?;
+        Some(MarketOutcome {
+            index,
+            name,
+            token_id: self.clob_token_ids.get(index).copied(),
+            price: self.outcome_prices.get(index).copied(),
+        })
+    }
+
+    /// Checks offline that `clob_token_ids` are the Polygon outcome tokens of `condition_id`.
+    pub fn verify_token_ids(&self) -> Result<()> {
+        let condition_id = self
+            .condition_id
+            .ok_or_else(|| Error::msg(format!("Market {} has no condition id", self.id)))?;
+        Ctf::new().verify_token_ids(condition_id, self.neg_risk, &self.clob_token_ids)
+    }
+}
+
+/// Daily liquidity reward budget of a market.
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
+pub struct ClobReward {
+    pub id: String,
+    #[serde(rename = "conditionId")]
+    pub condition_id: Option<ConditionId>,
+    #[serde(rename = "assetAddress")]
+    pub asset_address: Option<String>,
+    #[serde(rename = "rewardsAmount", default, deserialize_with = "deserialize_option_decimal")]
+    pub rewards_amount: Option<Decimal>,
+    #[serde(rename = "rewardsDailyRate", default, deserialize_with = "deserialize_option_decimal")]
+    pub rewards_daily_rate: Option<Decimal>,
+    #[serde(rename = "startDate")]
+    pub start_date: Option<String>,
+    #[serde(rename = "endDate")]
+    pub end_date: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
+pub struct Tag {
+    pub id: String,
+    pub label: Option<String>,
+    pub slug: Option<String>,
+    #[serde(rename = "forceShow")]
+    pub force_show: Option<bool>,
+    #[serde(rename = "forceHide")]
+    pub force_hide: Option<bool>,
+    #[serde(rename = "isCarousel")]
+    pub is_carousel: Option<bool>,
+}
+
+/// A recurring group of events, e.g. a sports league season or a weekly economic release.
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
+pub struct Series {
+    pub id: String,
+    pub ticker: Option<String>,
+    pub slug: Option<String>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    #[serde(rename = "seriesType")]
+    pub series_type: Option<String>,
+    /// e.g. `daily`, `weekly`
+    pub recurrence: Option<String>,
+    pub active: Option<bool>,
+    pub closed: Option<bool>,
+    pub archived: Option<bool>,
+    #[serde(default, deserialize_with = "deserialize_option_decimal")]
+    pub volume: Option<Decimal>,
+    #[serde(default, deserialize_with = "deserialize_option_decimal")]
+    pub liquidity: Option<Decimal>,
+    /// Only populated when the series is fetched on its own
+    #[serde(default)]
+    pub events: Vec<Event>,
+    #[serde(default)]
+    pub tags: Vec<Tag>,
+}
+
+/// Metadata of a sport: the tags and series its markets are filed under.
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
+pub struct Sport {
+    pub sport: String,
+    pub image: Option<String>,
+    pub resolution: Option<String>,
+    pub ordering: Option<String>,
+    /// Comma-separated tag ids, see [`Sport::tag_ids`]
+    #[serde(default)]
+    pub tags: String,
+    /// Series id of the sport's events
+    pub series: Option<String>,
+}
+
+impl Sport {
+    pub fn tag_ids(&self) -> impl Iterator<Item = &str> {
+        self.tags.split(',').map(str::trim).filter(|id| !id.is_empty())
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
+pub struct Team {
+    pub id: u64,
+    pub name: Option<String>,
+    pub league: Option<String>,
+    pub abbreviation: Option<String>,
+    pub alias: Option<String>,
+    pub record: Option<String>,
+    pub logo: Option<String>,
+}
+
+/// A Gamma event: a group of markets sharing a question, e.g. the outcomes of a multi-outcome
+/// (neg-risk) market.
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
+pub struct Event {
+    pub id: String,
+    pub ticker: Option<String>,
+    pub slug: Option<String>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    #[serde(rename = "startDate")]
+    pub start_date: Option<DateTime<Utc>>,
+    #[serde(rename = "endDate")]
+    pub end_date: Option<DateTime<Utc>>,
+    pub active: Option<bool>,
+    pub closed: Option<bool>,
+    pub archived: Option<bool>,
+    #[serde(rename = "negRisk", default)]
+    pub neg_risk: bool,
+    #[serde(rename = "negRiskMarketID")]
+    pub neg_risk_market_id: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_option_decimal")]
+    pub liquidity: Option<Decimal>,
+    #[serde(default, deserialize_with = "deserialize_option_decimal")]
+    pub volume: Option<Decimal>,
+    #[serde(rename = "openInterest", default, deserialize_with = "deserialize_option_decimal")]
+    pub open_interest: Option<Decimal>,
+    #[serde(default)]
+    pub markets: Vec<Market>,
+    #[serde(default)]
+    pub series: Vec<Series>,
+    #[serde(default)]
+    pub tags: Vec<Tag>,
+}
+
+/// A user's public profile, as embedded in search results and comments.
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
+pub struct Profile {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub pseudonym: Option<String>,
+    #[serde(rename = "displayUsernamePublic")]
+    pub display_username_public: Option<bool>,
+    pub bio: Option<String>,
+    #[serde(rename = "proxyWallet")]
+    pub proxy_wallet: Option<String>,
+    #[serde(rename = "baseAddress")]
+    pub base_address: Option<String>,
+    #[serde(rename = "profileImage")]
+    pub profile_image: Option<String>,
+    /// Holdings in the commented market, only sent when comments are listed with positions
+    #[serde(default)]
+    pub positions: Vec<ProfilePosition>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
+pub struct ProfilePosition {
+    #[serde(rename = "tokenId")]
+    pub token_id: TokenId,
+    #[serde(rename = "positionSize", default, deserialize_with = "deserialize_option_decimal")]
+    pub position_size: Option<Decimal>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
+pub struct SearchTag {
+    pub id: String,
+    pub label: Option<String>,
+    pub slug: Option<String>,
+    pub event_count: Option<u64>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
+pub struct SearchPagination {
+    #[serde(rename = "hasMore", default)]
+    pub has_more: bool,
+    #[serde(rename = "totalResults")]
+    pub total_results: Option<u64>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
+pub struct SearchResults {
+    #[serde(default)]
+    pub events: Vec<Event>,
+    #[serde(default)]
+    pub tags: Vec<SearchTag>,
+    #[serde(default)]
+    pub profiles: Vec<Profile>,
+    #[serde(default)]
+    pub pagination: SearchPagination,
+}
+
+impl SearchResults {
+    /// Markets of every matched event.
+    pub fn markets(&self) -> impl Iterator<Item = &Market> {
+        self.events.iter().flat_map(|event| &event.markets)
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
+pub struct Comment {
+    pub id: String,
+    pub body: Option<String>,
+    #[serde(rename = "parentEntityType")]
+    pub parent_entity_type: Option<String>,
+    #[serde(rename = "parentEntityID")]
+    pub parent_entity_id: Option<u64>,
+    /// Comment this one replies to, `None` for top-level comments
+    #[serde(rename = "parentCommentID")]
+    pub parent_comment_id: Option<String>,
+    #[serde(rename = "userAddress")]
+    pub user_address: Option<String>,
+    #[serde(rename = "replyAddress")]
+    pub reply_address: Option<String>,
+    #[serde(rename = "createdAt")]
+    pub created_at: Option<DateTime<Utc>>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: Option<DateTime<Utc>>,
+    pub profile: Option<Profile>,
+    #[serde(rename = "reactionCount", default)]
+    pub reaction_count: u64,
+    #[serde(rename = "reportCount", default)]
+    pub report_count: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum TradeSide {
+    Buy,
+    Sell,
+}
+
+impl TradeSide {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Buy => "BUY",
+            Self::Sell => "SELL",
+        }
+    }
+}
+
+/// An open position of a wallet, from the Data API.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Position {
+    pub proxy_wallet: String,
+    /// Token id of the held outcome
+    pub asset: TokenId,
+    pub condition_id: ConditionId,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub size: Decimal,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub avg_price: Decimal,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub initial_value: Decimal,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub current_value: Decimal,
+    /// Unrealised PnL in USDC
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub cash_pnl: Decimal,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub percent_pnl: Decimal,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub total_bought: Decimal,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub realized_pnl: Decimal,
+    #[serde(default, deserialize_with = "deserialize_option_decimal")]
+    pub percent_realized_pnl: Option<Decimal>,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub cur_price: Decimal,
+    #[serde(default)]
+    pub redeemable: bool,
+    #[serde(default)]
+    pub mergeable: bool,
+    pub title: Option<String>,
+    pub slug: Option<String>,
+    pub event_slug: Option<String>,
+    pub outcome: Option<String>,
+    pub outcome_index: Option<u32>,
+    pub opposite_outcome: Option<String>,
+    pub opposite_asset: Option<TokenId>,
+    pub end_date: Option<String>,
+    #[serde(default)]
+    pub negative_risk: bool,
+}
+
+/// A fully exited or resolved position of a wallet, from the Data API.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClosedPosition {
+    pub proxy_wallet: String,
+    pub asset: TokenId,
+    pub condition_id: ConditionId,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub avg_price: Decimal,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub total_bought: Decimal,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub realized_pnl: Decimal,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub cur_price: Decimal,
+    /// Unix seconds the position was closed
+    pub timestamp: Option<i64>,
+    pub title: Option<String>,
+    pub slug: Option<String>,
+    pub event_slug: Option<String>,
+    pub outcome: Option<String>,
+    pub outcome_index: Option<u32>,
+    pub opposite_outcome: Option<String>,
+    pub opposite_asset: Option<TokenId>,
+    pub end_date: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ActivityType {
+    Trade,
+    Split,
+    Merge,
+    Redeem,
+    Reward,
+    Conversion,
+    #[serde(other)]
+    Other,
+}
+
+impl ActivityType {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Trade => "TRADE",
+            Self::Split => "SPLIT",
+            Self::Merge => "MERGE",
+            Self::Redeem => "REDEEM",
+            Self::Reward => "REWARD",
+            Self::Conversion => "CONVERSION",
+            Self::Other => "OTHER",
         }
     }
 }
+
+/// An on-platform action of a wallet: a trade, split, merge, redemption, reward or conversion.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Activity {
+    pub proxy_wallet: String,
+    /// Unix seconds
+    pub timestamp: i64,
+    #[serde(default, deserialize_with = "deserialize_option_from_str")]
+    pub condition_id: Option<ConditionId>,
+    #[serde(rename = "type")]
+    pub kind: ActivityType,
+    /// Shares moved
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub size: Decimal,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub usdc_size: Decimal,
+    pub transaction_hash: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_option_decimal")]
+    pub price: Option<Decimal>,
+    #[serde(default, deserialize_with = "deserialize_option_from_str")]
+    pub asset: Option<TokenId>,
+    /// Only set for trades
+    #[serde(default, deserialize_with = "deserialize_option_from_str")]
+    pub side: Option<TradeSide>,
+    pub outcome_index: Option<u32>,
+    pub title: Option<String>,
+    pub slug: Option<String>,
+    pub event_slug: Option<String>,
+    pub outcome: Option<String>,
+}
+
+/// A fill from the Data API trade history.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    pub proxy_wallet: String,
+    pub side: TradeSide,
+    pub asset: TokenId,
+    pub condition_id: ConditionId,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub size: Decimal,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub price: Decimal,
+    /// Unix seconds
+    pub timestamp: i64,
+    pub title: Option<String>,
+    pub slug: Option<String>,
+    pub event_slug: Option<String>,
+    pub outcome: Option<String>,
+    pub outcome_index: Option<u32>,
+    pub name: Option<String>,
+    pub pseudonym: Option<String>,
+    pub transaction_hash: Option<String>,
+}
+
+/// Total USDC value of a wallet's positions.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct UserValue {
+    pub user: String,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub value: Decimal,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Holder {
+    pub proxy_wallet: String,
+    pub asset: TokenId,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub amount: Decimal,
+    pub outcome_index: Option<u32>,
+    pub name: Option<String>,
+    pub pseudonym: Option<String>,
+}
+
+/// Largest holders of one token of a market.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TokenHolders {
+    pub token: TokenId,
+    #[serde(default)]
+    pub holders: Vec<Holder>,
+}
+
+/// A value observed at `at`, as yielded by [`poll`](crate::poll).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Sample<T> {
+    pub at: DateTime<Utc>,
+    pub value: T,
+}
+
+/// Open interest of a market, `GLOBAL` when queried without markets.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OpenInterest {
+    pub market: String,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub value: Decimal,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MarketVolume {
+    pub market: ConditionId,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub value: Decimal,
+}
+
+/// Traded volume of an event and its markets.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct LiveVolume {
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub total: Decimal,
+    #[serde(default)]
+    pub markets: Vec<MarketVolume>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaderboardEntry {
+    pub rank: String,
+    pub proxy_wallet: String,
+    pub user_name: Option<String>,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub vol: Decimal,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub pnl: Decimal,
+    pub profile_image: Option<String>,
+    pub x_username: Option<String>,
+    #[serde(default)]
+    pub verified_badge: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// "Donald Trump" in the neg-risk 2024 presidential election event, as listed by Gamma.
+    fn trump_market() -> Market {
+        Market {
+            condition_id: Some(
+                "0xdd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917"
+                    .parse()
+                    .unwrap(),
+            ),
+            neg_risk: true,
+            clob_token_ids: vec![
+                "21742633143463906290569050155826241533067272736897614950488156847949938836455"
+                    .parse()
+                    .unwrap(),
+                "48331043336612883890938759509493159234755048973500640148014422747788308965732"
+                    .parse()
+                    .unwrap(),
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn verify_token_ids_accepts_gamma_ids() {
+        assert!(trump_market().verify_token_ids().is_ok());
+    }
+
+    #[test]
+    fn verify_token_ids_rejects_swapped_ids() {
+        let mut market = trump_market();
+        market.clob_token_ids.reverse();
+        assert!(market.verify_token_ids().is_err());
+    }
+
+    #[test]
+    fn verify_token_ids_rejects_mismatched_ids() {
+        let mut market = trump_market();
+        market.clob_token_ids[1] = TokenId::default();
+        assert!(market.verify_token_ids().is_err());
+
+        let mut market = trump_market();
+        market.neg_risk = false;
+        assert!(market.verify_token_ids().is_err());
+
+        let mut market = trump_market();
+        market.condition_id = Some(ConditionId::default());
+        assert!(market.verify_token_ids().is_err());
+
+        let mut market = trump_market();
+        market.condition_id = None;
+        assert!(market.verify_token_ids().is_err());
+    }
+
+    #[test]
+    fn empty_condition_id_deserializes_as_none() {
+        let markets: Vec<Market> = serde_json::from_str(
+            r#"[
+                {"id": "1", "conditionId": ""},
+                {"id": "2", "conditionId": null},
+                {"id": "3"},
+                {"id": "4", "conditionId": "0xdd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917"}
+            ]"#,
+        )
+        .unwrap();
+        let ids: Vec<_> = markets.iter().map(|market| market.condition_id).collect();
+        assert_eq!(ids, [None, None, None, trump_market().condition_id]);
+    }
+}
@@ -1,7 +1,138 @@
+use crate::types::Sample;
+use anyhow::{Error, Result};
+use chrono::{DateTime, Utc};
+use clob::deserialize_decimal;
+use futures::{Stream, TryStreamExt, stream};
+use reqwest::Response;
 use rust_decimal::Decimal;
-use serde::{de, Deserialize, Deserializer};
-use serde_json;
-use std::str::FromStr;
+use serde::{Deserialize, Deserializer, Serializer, de, de::DeserializeOwned, ser};
+use std::{future::Future, str::FromStr, time::Duration};
+use tokio::time::{Interval, MissedTickBehavior, interval};
+
+/// Page size used by the auto-paginating streams when the filter sets no `limit`.
+pub const DEFAULT_PAGE_LIMIT: u64 = 100;
+
+pub async fn into_result<T: DeserializeOwned>(response: Response) -> Result<T> {
+    let status = response.status();
+
+    if status.is_success() {
+        response.json().await.map_err(|e| e.into())
+    } else {
+        let message = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+
+        Err(Error::msg(format!("API error: {} ({})", message, status.as_u16())))
+    }
+}
+
+/// Streams every item of an offset-paginated endpoint, calling `fetch(limit, offset)` until a
+/// page comes back empty. The offset advances by the number of items received, so a server
+/// capping pages below `limit` does not truncate the results.
+pub fn paginate<'a, T, F, Fut>(
+    limit: u64,
+    offset: u64,
+    fetch: F,
+) -> impl Stream<Item = Result<T>> + 'a
+where
+    T: 'a,
+    F: Fn(u64, u64) -> Fut + 'a,
+    Fut: Future<Output = Result<Vec<T>>> + 'a,
+{
+    let limit = limit.max(1);
+    stream::try_unfold(Some(offset), move |offset| {
+        let page = offset.map(|offset| (offset, fetch(limit, offset)));
+        async move {
+            let Some((offset, page)) = page else { return Ok::<_, Error>(None) };
+            let items = page.await?;
+            let next = (!items.is_empty()).then_some(offset + items.len() as u64);
+            Ok(Some((stream::iter(items.into_iter().map(Ok)), next)))
+        }
+    })
+    .try_flatten()
+}
+
+/// Calls `fetch` every `period` (the first call is immediate) and yields each result stamped with
+/// the time of the call. Failed polls are yielded as errors without ending the stream.
+pub fn poll<'a, T, F, Fut>(period: Duration, fetch: F) -> impl Stream<Item = Result<Sample<T>>> + 'a
+where
+    T: 'a,
+    F: Fn() -> Fut + 'a,
+    Fut: Future<Output = Result<T>> + 'a,
+{
+    // The interval is created on first poll so the stream can be built outside a runtime
+    stream::unfold((None::<Interval>, fetch), move |(ticker, fetch)| async move {
+        let mut ticker = ticker.unwrap_or_else(|| {
+            let mut ticker = interval(period);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            ticker
+        });
+        ticker.tick().await;
+        let at = Utc::now();
+        let sample = fetch().await.map(|value| Sample { at, value });
+        Some((sample, (Some(ticker), fetch)))
+    })
+}
+
+pub(crate) fn push_opt<T: ToString>(
+    query: &mut Vec<(&'static str, String)>,
+    key: &'static str,
+    value: Option<T>,
+) {
+    if let Some(value) = value {
+        query.push((key, value.to_string()));
+    }
+}
+
+pub(crate) fn push_all<T: ToString>(
+    query: &mut Vec<(&'static str, String)>,
+    key: &'static str,
+    values: &[T],
+) {
+    query.extend(values.iter().map(|value| (key, value.to_string())));
+}
+
+pub(crate) fn push_date(
+    query: &mut Vec<(&'static str, String)>,
+    key: &'static str,
+    value: Option<DateTime<Utc>>,
+) {
+    push_opt(query, key, value.map(|date| date.to_rfc3339()));
+}
+
+pub(crate) fn push_joined<T: ToString>(
+    query: &mut Vec<(&'static str, String)>,
+    key: &'static str,
+    values: &[T],
+) {
+    if !values.is_empty() {
+        let joined = values.iter().map(ToString::to_string).collect::<Vec<_>>().join(",");
+        query.push((key, joined));
+    }
+}
+
+/// Deserializes an optional value from its string form, treating `""` and `null` as `None`.
+pub fn deserialize_option_from_str<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    match Option::<String>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(s) if s.is_empty() => Ok(None),
+        Some(s) => T::deserialize(de::value::StringDeserializer::<D::Error>::new(s)).map(Some),
+    }
+}
+
+/// Deserializes an optional `Decimal` sent as a JSON number, a numeric string or `null`.
+pub fn deserialize_option_decimal<'de, D>(deserializer: D) -> Result<Option<Decimal>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<serde_json::Value>::deserialize(deserializer)? {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(serde_json::Value::String(s)) if s.is_empty() => Ok(None),
+        Some(value) => deserialize_decimal(value).map(Some).map_err(de::Error::custom),
+    }
+}
 
 pub fn deserialize_decimal_vec_from_json_string<'de, D>(
     deserializer: D,
@@ -42,3 +173,136 @@ where
 
     Ok(string_vec)
 }
+
+/// Deserializes a JSON-encoded array of strings, parsing each element with `FromStr`.
+pub fn deserialize_vec_from_json_string<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    deserialize_string_vec_from_json_string(deserializer)?
+        .into_iter()
+        .map(|s| {
+            s.parse().map_err(|e| de::Error::custom(format!("Failed to parse '{}': {}", s, e)))
+        })
+        .collect()
+}
+
+/// Serializes a list as a JSON-encoded array of strings, the inverse of the `*_from_json_string`
+/// deserializers.
+pub fn serialize_vec_as_json_string<S, T>(values: &[T], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: ToString,
+{
+    let strings: Vec<String> = values.iter().map(ToString::to_string).collect();
+    let json_string = serde_json::to_string(&strings)
+        .map_err(|e| ser::Error::custom(format!("Failed to encode JSON array: {}", e)))?;
+    serializer.serialize_str(&json_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use serde::Deserialize;
+    use std::sync::{Arc, Mutex};
+
+    /// Pages of `0..total`, at most `cap` items each, recording every `(limit, offset)` asked for.
+    async fn collect_pages(
+        total: u64,
+        cap: u64,
+        limit: u64,
+        offset: u64,
+    ) -> (Vec<u64>, Vec<(u64, u64)>) {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let recorded = calls.clone();
+        let items = paginate(limit, offset, move |limit, offset| {
+            recorded.lock().unwrap().push((limit, offset));
+            let end = total.min(offset + limit.min(cap));
+            async move { Ok((offset.min(end)..end).collect::<Vec<_>>()) }
+        })
+        .try_collect()
+        .await
+        .unwrap();
+        let calls = calls.lock().unwrap().clone();
+        (items, calls)
+    }
+
+    #[tokio::test]
+    async fn paginate_reads_until_empty_page() {
+        let (items, calls) = collect_pages(5, 100, 2, 0).await;
+        assert_eq!(items, [0, 1, 2, 3, 4]);
+        assert_eq!(calls, [(2, 0), (2, 2), (2, 4), (2, 5)]);
+    }
+
+    #[tokio::test]
+    async fn paginate_follows_server_page_cap() {
+        // The server returns at most 3 items whatever the limit
+        let (items, calls) = collect_pages(7, 3, 10, 0).await;
+        assert_eq!(items, (0..7).collect::<Vec<_>>());
+        assert_eq!(calls, [(10, 0), (10, 3), (10, 6), (10, 7)]);
+    }
+
+    #[tokio::test]
+    async fn paginate_starts_at_offset() {
+        let (items, calls) = collect_pages(4, 100, 0, 2).await;
+        assert_eq!(items, [2, 3]);
+        // A zero limit is raised to one
+        assert_eq!(calls, [(1, 2), (1, 3), (1, 4)]);
+    }
+
+    #[tokio::test]
+    async fn paginate_stops_on_error() {
+        let results: Vec<Result<u64>> = paginate(2, 0, |_, offset| async move {
+            if offset == 0 { Ok(vec![0, 1]) } else { Err(Error::msg("boom")) }
+        })
+        .collect()
+        .await;
+        assert_eq!(results.len(), 3);
+        assert!(results[2].is_err());
+    }
+
+    #[test]
+    fn push_helpers_skip_unset_values() {
+        let mut query = Vec::new();
+        push_opt(&mut query, "a", None::<u64>);
+        push_opt(&mut query, "b", Some(1));
+        push_all(&mut query, "c", &["x", "y"]);
+        push_joined(&mut query, "d", &["x", "y"]);
+        push_joined::<&str>(&mut query, "e", &[]);
+        push_date(&mut query, "f", DateTime::from_timestamp(0, 0));
+        assert_eq!(
+            query,
+            [
+                ("b", "1".to_string()),
+                ("c", "x".to_string()),
+                ("c", "y".to_string()),
+                ("d", "x,y".to_string()),
+                ("f", "1970-01-01T00:00:00+00:00".to_string()),
+            ]
+        );
+    }
+
+    #[derive(Deserialize)]
+    struct Amount {
+        #[serde(default, deserialize_with = "deserialize_option_decimal")]
+        value: Option<Decimal>,
+    }
+
+    fn parse(json: &str) -> Option<Decimal> {
+        serde_json::from_str::<Amount>(json).unwrap().value
+    }
+
+    #[test]
+    fn option_decimal_accepts_numbers_strings_and_blanks() {
+        assert_eq!(parse(r#"{"value": 0.25}"#), Some(Decimal::new(25, 2)));
+        assert_eq!(parse(r#"{"value": "0.25"}"#), Some(Decimal::new(25, 2)));
+        assert_eq!(parse(r#"{"value": "1e-3"}"#), Some(Decimal::new(1, 3)));
+        assert_eq!(parse(r#"{"value": ""}"#), None);
+        assert_eq!(parse(r#"{"value": null}"#), None);
+        assert_eq!(parse("{}"), None);
+        assert!(serde_json::from_str::<Amount>(r#"{"value": true}"#).is_err());
+    }
+}
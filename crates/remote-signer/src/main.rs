@@ -0,0 +1,75 @@
+//! Reference signing daemon for `clob::RemoteSigner`.
+//!
+//! Holds the private key and signs `Order` / `ClobAuth` payloads that pass its
+//! `clob::SigningPolicy`, so strategy processes never see the key.
+//!
+//! `ClobAuth` payloads are signed for any timestamp, nonce and message, so every process that
+//! can reach the daemon can create or derive API keys for the wallet. Keep it on a loopback or
+//! otherwise trusted address.
+//!
+//! Configuration (environment):
+//! - `REMOTE_SIGNER_PRIVATE_KEY` – hex private key (required)
+//! - `REMOTE_SIGNER_LISTEN` – listen address, defaults to `127.0.0.1:8645`
+//! - `REMOTE_SIGNER_POLICY` – path to a JSON `SigningPolicy`, defaults to the built-in policy
+
+use alloy_primitives::hex::encode_prefixed;
+use alloy_signer::SignerSync;
+use alloy_signer_local::PrivateKeySigner;
+use anyhow::{Context, Result};
+use axum::{
+    Json, Router,
+    extract::State,
+    http::StatusCode,
+    routing::{get, post},
+};
+use clob::{RemoteAddressResponse, RemoteSignRequest, RemoteSignResponse, SigningPolicy};
+use std::{env, fs, str::FromStr, sync::Arc};
+
+const DEFAULT_LISTEN_ADDR: &str = "127.0.0.1:8645";
+
+#[derive(Debug)]
+struct AppState {
+    wallet: PrivateKeySigner,
+    policy: SigningPolicy,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let key = env::var("REMOTE_SIGNER_PRIVATE_KEY").context("REMOTE_SIGNER_PRIVATE_KEY not set")?;
+    let wallet = PrivateKeySigner::from_str(&key).context("Invalid private key")?;
+    let policy = match env::var("REMOTE_SIGNER_POLICY") {
+        Ok(path) => serde_json::from_str(&fs::read_to_string(&path)?)
+            .with_context(|| format!("Invalid policy file {path}"))?,
+        Err(_) => SigningPolicy::default(),
+    };
+    let listen = env::var("REMOTE_SIGNER_LISTEN").unwrap_or_else(|_| DEFAULT_LISTEN_ADDR.into());
+
+    let state = Arc::new(AppState { wallet, policy });
+    let app =
+        Router::new().route("/address", get(address)).route("/sign", post(sign)).with_state(state);
+
+    let listener = tokio::net::TcpListener::bind(&listen).await?;
+    axum::serve(listener, app).await?;
+    Ok(())
+}
+
+async fn address(State(state): State<Arc<AppState>>) -> Json<RemoteAddressResponse> {
+    Json(RemoteAddressResponse { address: state.wallet.address() })
+}
+
+async fn sign(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<RemoteSignRequest>,
+) -> Result<Json<RemoteSignResponse>, (StatusCode, String)> {
+    let hash = state
+        .policy
+        .authorize(state.wallet.address(), &request)
+        .map_err(|e| (StatusCode::FORBIDDEN, e.to_string()))?;
+
+    let signature = state
+        .wallet
+        .sign_hash_sync(&hash)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(RemoteSignResponse { signature: encode_prefixed(signature.as_bytes()) }))
+}